@@ -0,0 +1,98 @@
+use crate::Memory;
+use ic_stable_structures::{StableBTreeMap, Storable};
+
+/// A value of a `Cache`, which knows when it was created.
+pub trait Cached: Storable {
+    /// Nanoseconds since the epoch.
+    fn created_at(&self) -> u64;
+}
+
+/// A cache of LLM answers in stable memory. Entries expire after a time to
+/// live and, when the cache is full, the oldest entry makes room for a new
+/// one. A second map orders the keys by the time their entry was created, so
+/// making room does not scan the cache.
+pub struct Cache<V: Cached> {
+    entries: StableBTreeMap<String, V, Memory>,
+    /// The key of every entry, keyed by its creation time in hex followed by
+    /// the key, which keeps the ages unique and in the order of creation.
+    ages: StableBTreeMap<String, String, Memory>,
+    ttl_nanos: u64,
+    capacity: u64,
+}
+
+fn age(created_at: u64, key: &str) -> String {
+    format!("{:016x}/{}", created_at, key)
+}
+
+impl<V: Cached> Cache<V> {
+    /// Opens the cache. Entries cached before the ages were kept are indexed
+    /// once.
+    pub fn init(entries: Memory, ages: Memory, ttl_nanos: u64, capacity: u64) -> Self {
+        let mut cache = Self {
+            entries: StableBTreeMap::init(entries),
+            ages: StableBTreeMap::init(ages),
+            ttl_nanos,
+            capacity,
+        };
+        if cache.ages.is_empty() && !cache.entries.is_empty() {
+            for (key, value) in cache.entries.iter() {
+                cache.ages.insert(age(value.created_at(), &key), key);
+            }
+        }
+        cache
+    }
+
+    fn is_expired(&self, value: &V, now: u64) -> bool {
+        now.saturating_sub(value.created_at()) >= self.ttl_nanos
+    }
+
+    /// Returns the entry of the key, unless it has expired.
+    pub fn get(&self, key: &str, now: u64) -> Option<V> {
+        self.entries
+            .get(&key.to_string())
+            .filter(|value| !self.is_expired(value, now))
+    }
+
+    /// Caches the value under the key, replacing the previous entry of the
+    /// key. Expired entries are deleted first and, if the cache is still full,
+    /// the oldest one.
+    pub fn insert(&mut self, key: String, value: V, now: u64) {
+        self.remove(&key);
+        while let Some((oldest, oldest_key)) = self.ages.first_key_value() {
+            let created_at = u64::from_str_radix(&oldest[..16], 16).unwrap_or_default();
+            if now.saturating_sub(created_at) < self.ttl_nanos && self.entries.len() < self.capacity
+            {
+                break;
+            }
+            self.ages.remove(&oldest);
+            self.entries.remove(&oldest_key);
+        }
+        self.ages.insert(age(value.created_at(), &key), key.clone());
+        self.entries.insert(key, value);
+    }
+
+    /// Deletes the entry of the key. Returns whether it existed.
+    pub fn remove(&mut self, key: &str) -> bool {
+        match self.entries.remove(&key.to_string()) {
+            Some(value) => {
+                self.ages.remove(&age(value.created_at(), key));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Deletes the entries that match the predicate and returns their number.
+    pub fn remove_where(&mut self, predicate: impl Fn(&V) -> bool) -> u64 {
+        let keys: Vec<String> = self
+            .entries
+            .iter()
+            .filter(|(_, value)| predicate(value))
+            .map(|(key, _)| key)
+            .collect();
+        for key in &keys {
+            self.remove(key);
+        }
+        keys.len() as u64
+    }
+}
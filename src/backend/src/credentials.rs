@@ -0,0 +1,33 @@
+use crate::{Memory, CREDENTIALS_MEMORY_ID, MEMORY_MANAGER};
+use ic_stable_structures::StableBTreeMap;
+use std::cell::RefCell;
+
+thread_local! {
+    // API keys keyed by provider. They are only read by outcalls and are
+    // never returned by any endpoint.
+    static API_KEYS: RefCell<StableBTreeMap<String, String, Memory>> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(CREDENTIALS_MEMORY_ID))),
+    );
+}
+
+/// Stores the API key of the provider, replacing the previous one.
+pub fn set(provider: &str, api_key: String) -> Result<(), String> {
+    let api_key = api_key.trim().to_string();
+    if api_key.is_empty() {
+        return Err("API key must not be empty".to_string());
+    }
+    API_KEYS.with_borrow_mut(|keys| {
+        keys.insert(provider.to_string(), api_key);
+    });
+    Ok(())
+}
+
+/// Returns the API key of the provider, if one was set.
+pub fn api_key(provider: &str) -> Option<String> {
+    API_KEYS.with_borrow(|keys| keys.get(&provider.to_string()))
+}
+
+/// Whether an API key was set for the provider.
+pub fn has(provider: &str) -> bool {
+    API_KEYS.with_borrow(|keys| keys.contains_key(&provider.to_string()))
+}
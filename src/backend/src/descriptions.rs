@@ -0,0 +1,130 @@
+use crate::{
+    cache::{Cache, Cached},
+    llm, names, LlmError, LlmErrorKind, LlmResponse, LlmResult, DESCRIPTIONS_MEMORY_ID,
+    DESCRIPTION_AGES_MEMORY_ID, MEMORY_MANAGER,
+};
+use candid::{CandidType, Deserialize};
+use ic_stable_structures::{storable::Bound, Storable};
+use std::borrow::Cow;
+use std::cell::RefCell;
+
+/// Descriptions are regenerated after 30 days.
+const TTL_NANOS: u64 = 30 * 24 * 60 * 60 * 1_000_000_000;
+
+/// The cache keeps at most this many descriptions. When it is full the oldest
+/// one makes room for a new one.
+const MAX_DESCRIPTIONS: u64 = 5000;
+
+/// The language of descriptions requested without a locale.
+const DEFAULT_LANGUAGE: &str = "en";
+
+#[derive(CandidType, Deserialize)]
+struct Description {
+    label: String,
+    response: LlmResponse,
+    /// The time of the outcall in nanoseconds since the epoch.
+    created_at: u64,
+}
+
+impl Storable for Description {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(candid::encode_one(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        candid::decode_one(&bytes).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+impl Cached for Description {
+    fn created_at(&self) -> u64 {
+        self.created_at
+    }
+}
+
+thread_local! {
+    static DESCRIPTIONS: RefCell<Cache<Description>> = RefCell::new(MEMORY_MANAGER.with(|m| {
+        let m = m.borrow();
+        Cache::init(
+            m.get(DESCRIPTIONS_MEMORY_ID),
+            m.get(DESCRIPTION_AGES_MEMORY_ID),
+            TTL_NANOS,
+            MAX_DESCRIPTIONS,
+        )
+    }));
+}
+
+/// Returns the language descriptions for the locale are written in. Locales
+/// whose language is not an ISO 639-1 code fall back to English.
+fn language(locale: Option<&str>) -> String {
+    locale
+        .map(names::language)
+        .filter(|language| names::is_language(language))
+        .unwrap_or_else(|| DEFAULT_LANGUAGE.to_string())
+}
+
+/// The key of the description of an ImageNet label, so that the label and
+/// its common names in any case share one description.
+fn key(label: &str, language: &str) -> String {
+    format!("{}/{}/{}", llm::PROMPT_VERSION, language, label)
+}
+
+/// Returns the cached description of the label, unless it has expired.
+pub fn get(label: &str, locale: Option<&str>) -> Option<LlmResponse> {
+    let label = names::canonical_label(label)?;
+    let key = key(label, &language(locale));
+    let now = ic_cdk::api::time();
+    DESCRIPTIONS
+        .with_borrow(|descriptions| descriptions.get(&key, now))
+        .map(|description| description.response)
+}
+
+/// Returns the cached description of the label or asks the LLM for one and
+/// caches it. Errors are not cached, and labels that are neither an ImageNet
+/// label nor the common name of one are rejected before the outcall.
+pub async fn describe(label: String, locale: Option<String>) -> LlmResult {
+    let Some(label) = names::canonical_label(&label) else {
+        return LlmResult::Err(LlmError {
+            kind: LlmErrorKind::InvalidRequest,
+            status: None,
+            message: format!("unknown label {:?}", label),
+        });
+    };
+    if let Some(response) = get(label, locale.as_deref()) {
+        return LlmResult::Ok(response);
+    }
+    let language = language(locale.as_deref());
+    let prompt = if language == DEFAULT_LANGUAGE {
+        label.to_string()
+    } else {
+        format!(
+            "{}\n\nAnswer in the language with the ISO 639-1 code \"{}\".",
+            label, language
+        )
+    };
+    let result = llm::complete(llm::PET_BEHAVIORIST_PROMPT, prompt, None).await;
+    if let LlmResult::Ok(response) = &result {
+        let now = ic_cdk::api::time();
+        let description = Description {
+            label: label.to_string(),
+            response: response.clone(),
+            created_at: now,
+        };
+        DESCRIPTIONS.with_borrow_mut(|descriptions| {
+            descriptions.insert(key(label, &language), description, now)
+        });
+    }
+    result
+}
+
+/// Deletes the cached descriptions of the label in every language, or all
+/// cached descriptions if no label is given. Returns the number deleted.
+pub fn invalidate(label: Option<&str>) -> u64 {
+    let label = label.map(|label| names::canonical_label(label).unwrap_or(label));
+    DESCRIPTIONS.with_borrow_mut(|descriptions| {
+        descriptions
+            .remove_where(|description| label.map_or(true, |label| description.label == label))
+    })
+}
@@ -0,0 +1,140 @@
+use crate::{
+    onnx, preprocess,
+    principal_key::{self, Key},
+    Classification, ClassifyOptions, HistoryEntry, HistoryPage, Memory, HISTORY_MEMORY_ID,
+    MEMORY_MANAGER,
+};
+use candid::Principal;
+use ic_stable_structures::{storable::Bound, StableBTreeMap, Storable};
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::cell::RefCell;
+
+/// Only the most probable labels of a classification are kept.
+const MAX_RESULTS: usize = 5;
+
+/// The oldest entries of a principal are deleted beyond this.
+const MAX_ENTRIES: usize = 1000;
+
+const DEFAULT_PAGE_SIZE: u32 = 20;
+const MAX_PAGE_SIZE: u32 = 100;
+
+impl Storable for HistoryEntry {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(candid::encode_one(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        candid::decode_one(&bytes).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+thread_local! {
+    static HISTORY: RefCell<StableBTreeMap<Key, HistoryEntry, Memory>> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(HISTORY_MEMORY_ID))),
+    );
+}
+
+/// What is known about a classification before the model runs.
+pub struct Draft {
+    owner: Principal,
+    model_id: String,
+    image_sha256: Vec<u8>,
+    thumbnail: Option<Vec<u8>>,
+}
+
+/// Prepares the history entry of a classification, or returns `None` for the
+/// anonymous principal, which is shared by everyone.
+pub fn draft(owner: Principal, image: &[u8], options: &ClassifyOptions) -> Option<Draft> {
+    if owner == Principal::anonymous() {
+        return None;
+    }
+    Some(Draft {
+        owner,
+        model_id: options
+            .model_id
+            .clone()
+            .unwrap_or_else(|| onnx::DEFAULT_MODEL_ID.to_string()),
+        image_sha256: Sha256::digest(image).to_vec(),
+        thumbnail: options
+            .thumbnail
+            .unwrap_or(false)
+            .then(|| preprocess::thumbnail(image))
+            .flatten(),
+    })
+}
+
+/// Adds the classification to the history of its owner and deletes the
+/// oldest entries beyond `MAX_ENTRIES`. `animal_score` is set if the image
+/// did not look like an animal.
+pub fn record(draft: Draft, results: &[Classification], animal_score: Option<f32>) {
+    let timestamp = ic_cdk::api::time();
+    HISTORY.with_borrow_mut(|history| {
+        // Ids count down with time, so the newest entry comes first.
+        let mut id = u64::MAX - timestamp;
+        while history.contains_key(&Key::new(draft.owner, id)) {
+            id -= 1;
+        }
+        history.insert(
+            Key::new(draft.owner, id),
+            HistoryEntry {
+                id,
+                timestamp,
+                model_id: draft.model_id,
+                results: results.iter().take(MAX_RESULTS).cloned().collect(),
+                animal_score,
+                image_sha256: draft.image_sha256,
+                thumbnail: draft.thumbnail,
+            },
+        );
+        let expired: Vec<Key> = history
+            .range(principal_key::range(draft.owner, None))
+            .skip(MAX_ENTRIES)
+            .map(|(key, _)| key)
+            .collect();
+        for key in expired {
+            history.remove(&key);
+        }
+    });
+}
+
+/// Returns up to `limit` entries of the owner that follow the entry with id
+/// `cursor`, newest first.
+pub fn page(owner: Principal, cursor: Option<u64>, limit: Option<u32>) -> HistoryPage {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE) as usize;
+    HISTORY.with_borrow(|history| {
+        let mut entries: Vec<HistoryEntry> = history
+            .range(principal_key::range(owner, cursor))
+            .take(limit + 1)
+            .map(|(_, entry)| entry)
+            .collect();
+        let next = if entries.len() > limit {
+            entries.truncate(limit);
+            entries.last().map(|entry| entry.id)
+        } else {
+            None
+        };
+        HistoryPage { entries, next }
+    })
+}
+
+/// Deletes an entry of the owner. Returns whether it existed.
+pub fn delete(owner: Principal, id: u64) -> bool {
+    HISTORY.with_borrow_mut(|history| history.remove(&Key::new(owner, id)).is_some())
+}
+
+/// Deletes all entries of the owner and returns how many there were.
+pub fn clear(owner: Principal) -> u64 {
+    HISTORY.with_borrow_mut(|history| {
+        let keys: Vec<Key> = history
+            .range(principal_key::range(owner, None))
+            .map(|(key, _)| key)
+            .collect();
+        for key in &keys {
+            history.remove(key);
+        }
+        keys.len() as u64
+    })
+}
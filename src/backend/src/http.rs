@@ -0,0 +1,252 @@
+use crate::{
+    classify_image_v2, onnx, ClassificationResultV2, ClassifyError, ClassifyOptions, Crop,
+    HeaderField, HttpRequest, HttpResponse, Rank,
+};
+use serde::Serialize;
+
+const WASM_PAGE_SIZE: u64 = 65536;
+
+#[derive(Serialize)]
+struct Health {
+    status: &'static str,
+    models: usize,
+}
+
+#[derive(Serialize)]
+struct Error {
+    error: String,
+}
+
+/// Routes a request of the HTTP gateway. Classification changes no state but
+/// is only served in `update` calls so that the result is certified by
+/// consensus; in query calls the gateway is asked to upgrade the request.
+pub fn handle(request: HttpRequest, update: bool) -> HttpResponse {
+    let (path, query) = request
+        .url
+        .split_once('?')
+        .unwrap_or((request.url.as_str(), ""));
+    let params = parse_query(query);
+    match (request.method.as_str(), path) {
+        ("GET", "/health") => json(
+            200,
+            &Health {
+                status: "ok",
+                models: onnx::models().len(),
+            },
+        ),
+        ("GET", "/metrics") => metrics(),
+        ("GET", "/api/models") => json(200, &onnx::models()),
+        ("GET", "/api/labels") => {
+            let model_id = param(&params, "model_id").unwrap_or(onnx::DEFAULT_MODEL_ID);
+            match onnx::labels(model_id) {
+                Ok(labels) => json(200, &labels),
+                Err(message) => error(404, message),
+            }
+        }
+        ("POST", "/api/classify") if !update => HttpResponse {
+            status_code: 200,
+            headers: vec![],
+            body: vec![],
+            upgrade: Some(true),
+        },
+        ("POST", "/api/classify") => classify(request.body, &params),
+        (_, "/health" | "/metrics" | "/api/models" | "/api/labels" | "/api/classify") => {
+            error(405, "method not allowed".to_string())
+        }
+        _ => error(404, "not found".to_string()),
+    }
+}
+
+/// Classifies the raw image in the body with the options given as query
+/// parameters, e.g. `/api/classify?top_k=3&locale=id`.
+fn classify(image: Vec<u8>, params: &[(String, String)]) -> HttpResponse {
+    let result = match options(params) {
+        Ok(options) => classify_image_v2(image, options),
+        Err(message) => ClassificationResultV2::Err(ClassifyError::InvalidRequest { message }),
+    };
+    let status_code = match &result {
+        ClassificationResultV2::Err(err) => status(err),
+        _ => 200,
+    };
+    json(status_code, &result)
+}
+
+fn status(err: &ClassifyError) -> u16 {
+    match err {
+        ClassifyError::ModelNotLoaded { .. } => 404,
+        ClassifyError::DecodeFailed { .. } | ClassifyError::InvalidRequest { .. } => 400,
+        ClassifyError::ImageTooLarge { .. } => 413,
+        ClassifyError::UnsupportedFormat(_) => 415,
+        ClassifyError::ImageTooSmall { .. } => 422,
+        ClassifyError::QuotaExceeded { .. } => 429,
+        ClassifyError::InferenceFailed { .. } => 500,
+    }
+}
+
+/// Reads `ClassifyOptions` from the query parameters. Labels to include or
+/// exclude are given by repeating `include_label` or `exclude_label`.
+fn options(params: &[(String, String)]) -> Result<ClassifyOptions, String> {
+    let labels = |name: &str| {
+        let labels: Vec<_> = params
+            .iter()
+            .filter(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+            .collect();
+        (!labels.is_empty()).then_some(labels)
+    };
+    Ok(ClassifyOptions {
+        model_id: param(params, "model_id").map(str::to_string),
+        top_k: parse(params, "top_k")?,
+        min_score: parse(params, "min_score")?,
+        include_labels: labels("include_label"),
+        exclude_labels: labels("exclude_label"),
+        animals_only: parse(params, "animals_only")?,
+        rollup: param(params, "rollup").map(rank).transpose()?,
+        locale: param(params, "locale").map(str::to_string),
+        crop: param(params, "crop").map(crop).transpose()?,
+        thumbnail: None,
+    })
+}
+
+fn rank(value: &str) -> Result<Rank, String> {
+    match value {
+        "label" => Ok(Rank::Label),
+        "species" => Ok(Rank::Species),
+        "family" => Ok(Rank::Family),
+        "order" => Ok(Rank::Order),
+        "class" => Ok(Rank::Class),
+        _ => Err(format!("invalid rollup {}", value)),
+    }
+}
+
+fn crop(value: &str) -> Result<Crop, String> {
+    match value {
+        "center" => Ok(Crop::Center),
+        "stretch" => Ok(Crop::Stretch),
+        _ => Err(format!("invalid crop {}", value)),
+    }
+}
+
+/// Exposes the state of the canister in the Prometheus text format.
+fn metrics() -> HttpResponse {
+    let metrics = [
+        (
+            "models_loaded",
+            "Number of models that can be used.",
+            onnx::models().len() as u128,
+        ),
+        (
+            "stable_memory_bytes",
+            "Size of the stable memory in bytes.",
+            (ic_cdk::api::stable::stable64_size() * WASM_PAGE_SIZE) as u128,
+        ),
+        (
+            "heap_memory_bytes",
+            "Size of the heap memory in bytes.",
+            heap_memory_size() as u128,
+        ),
+        (
+            "cycles_balance",
+            "Cycles balance of the canister.",
+            ic_cdk::api::canister_balance128(),
+        ),
+    ];
+    let body: String = metrics
+        .iter()
+        .map(|(name, help, value)| {
+            format!("# HELP {name} {help}\n# TYPE {name} gauge\n{name} {value}\n")
+        })
+        .collect();
+    HttpResponse {
+        status_code: 200,
+        headers: vec![header("Content-Type", "text/plain; version=0.0.4")],
+        body: body.into_bytes(),
+        upgrade: None,
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn heap_memory_size() -> u64 {
+    core::arch::wasm32::memory_size(0) as u64 * WASM_PAGE_SIZE
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn heap_memory_size() -> u64 {
+    0
+}
+
+fn json(status_code: u16, value: &impl Serialize) -> HttpResponse {
+    HttpResponse {
+        status_code,
+        headers: vec![header("Content-Type", "application/json")],
+        body: serde_json::to_vec(value).unwrap(),
+        upgrade: None,
+    }
+}
+
+fn error(status_code: u16, error: String) -> HttpResponse {
+    json(status_code, &Error { error })
+}
+
+fn header(name: &str, value: &str) -> HeaderField {
+    (name.to_string(), value.to_string())
+}
+
+fn param<'a>(params: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+fn parse<T: std::str::FromStr>(
+    params: &[(String, String)],
+    name: &str,
+) -> Result<Option<T>, String> {
+    param(params, name)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| format!("invalid {} {}", name, value))
+        })
+        .transpose()
+}
+
+/// Splits a query string into its decoded key-value pairs.
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode(key), decode(value))
+        })
+        .collect()
+}
+
+/// Decodes `+` and percent-encoded bytes of a query string component.
+fn decode(component: &str) -> String {
+    let bytes = component.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let byte = match bytes[i] {
+            b'+' => b' ',
+            b'%' => match bytes
+                .get(i + 1..i + 3)
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                Some(byte) => {
+                    i += 2;
+                    byte
+                }
+                None => b'%',
+            },
+            byte => byte,
+        };
+        decoded.push(byte);
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
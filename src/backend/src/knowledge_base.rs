@@ -0,0 +1,214 @@
+use crate::{
+    names, species::Sheet, taxonomy, KnowledgeBaseInfo, Memory, ProfileSource, SpeciesProfile,
+    KNOWLEDGE_BASE_MEMORY_ID, MEMORY_MANAGER,
+};
+use ic_stable_structures::StableCell;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+/// Compact facts about the domestic animals and every family and class of the
+/// ImageNet animals, used until a controller uploads a more detailed knowledge
+/// base. It has no entries for individual breeds or wild species: a dog breed
+/// gets the facts of the dog and a wild animal those of its family.
+const EMBEDDED: &str = include_str!("../assets/species_kb.json");
+
+/// The knowledge base is kept on the heap while it is uploaded.
+const MAX_LEN: u64 = 8 * 1024 * 1024;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KnowledgeBase {
+    /// Must grow with every upload.
+    version: u32,
+    /// Fact sheets keyed by ImageNet label or by the name of a species,
+    /// family, order or class of the taxonomy. Keys are matched ignoring case.
+    entries: BTreeMap<String, Sheet>,
+    /// Whether this is `EMBEDDED`.
+    #[serde(skip)]
+    embedded: bool,
+}
+
+/// An upload that has been started but not committed yet.
+struct Upload {
+    total_len: u64,
+    sha256: [u8; 32],
+    bytes: Vec<u8>,
+}
+
+thread_local! {
+    static KNOWLEDGE_BASE: RefCell<Option<KnowledgeBase>> = RefCell::new(None);
+
+    static UPLOAD: RefCell<Option<Upload>> = RefCell::new(None);
+
+    // The JSON of the uploaded knowledge base. Empty if none was uploaded.
+    static STORED: RefCell<StableCell<Vec<u8>, Memory>> = RefCell::new(
+        StableCell::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(KNOWLEDGE_BASE_MEMORY_ID)),
+            vec![],
+        )
+        .unwrap(),
+    );
+}
+
+/// Parses and validates the JSON of a knowledge base.
+fn parse(bytes: &[u8]) -> Result<KnowledgeBase, String> {
+    let knowledge_base: KnowledgeBase =
+        serde_json::from_slice(bytes).map_err(|err| format!("invalid knowledge base: {}", err))?;
+    if knowledge_base.entries.is_empty() {
+        return Err("knowledge base must not be empty".to_string());
+    }
+    let mut entries = BTreeMap::new();
+    for (name, sheet) in knowledge_base.entries {
+        let source = ProfileSource::KnowledgeBase {
+            version: knowledge_base.version,
+            taxon: name.clone(),
+        };
+        sheet
+            .clone()
+            .into_profile(name.clone(), source)
+            .map_err(|err| format!("invalid entry {}: {}", name, err))?;
+        if entries.insert(name.to_lowercase(), sheet).is_some() {
+            return Err(format!("duplicate entry {}", name));
+        }
+    }
+    Ok(KnowledgeBase {
+        version: knowledge_base.version,
+        entries,
+        embedded: false,
+    })
+}
+
+fn embedded() -> KnowledgeBase {
+    KnowledgeBase {
+        embedded: true,
+        ..parse(EMBEDDED.as_bytes()).unwrap()
+    }
+}
+
+/// Loads the uploaded knowledge base, or the embedded one if none was
+/// uploaded or the uploaded one no longer parses, e.g. after an upgrade made
+/// the validation stricter.
+pub fn setup() {
+    let stored = STORED.with_borrow(|stored| stored.get().clone());
+    let knowledge_base = if stored.is_empty() {
+        embedded()
+    } else {
+        parse(&stored).unwrap_or_else(|err| {
+            ic_cdk::println!("Failed to load the uploaded knowledge base: {}", err);
+            embedded()
+        })
+    };
+    KNOWLEDGE_BASE.with_borrow_mut(|current| *current = Some(knowledge_base));
+}
+
+/// Returns the fact sheet of the most specific entry that covers the label:
+/// the label itself, or its species, family, order or class.
+pub fn profile(label: &str) -> Option<SpeciesProfile> {
+    let mut taxa = vec![label.trim().to_string()];
+    if let Some(lineage) = names::synset(label).and_then(taxonomy::lineage) {
+        taxa.push(lineage.species);
+        taxa.extend(lineage.family);
+        taxa.extend(lineage.order);
+        taxa.extend(lineage.class);
+    }
+    KNOWLEDGE_BASE.with_borrow(|knowledge_base| {
+        let knowledge_base = knowledge_base.as_ref()?;
+        taxa.into_iter().find_map(|taxon| {
+            let sheet = knowledge_base.entries.get(&taxon.to_lowercase())?;
+            let source = ProfileSource::KnowledgeBase {
+                version: knowledge_base.version,
+                taxon,
+            };
+            sheet.clone().into_profile(label.to_string(), source).ok()
+        })
+    })
+}
+
+pub fn info() -> KnowledgeBaseInfo {
+    KNOWLEDGE_BASE.with_borrow(|knowledge_base| match knowledge_base {
+        Some(knowledge_base) => KnowledgeBaseInfo {
+            version: knowledge_base.version,
+            entries: knowledge_base.entries.len() as u32,
+            embedded: knowledge_base.embedded,
+        },
+        None => KnowledgeBaseInfo {
+            version: 0,
+            entries: 0,
+            embedded: false,
+        },
+    })
+}
+
+/// Starts a new upload of `total_len` bytes whose SHA-256 must match `sha256`.
+/// An upload that is still in progress is discarded.
+pub fn begin(total_len: u64, sha256: Vec<u8>) -> Result<(), String> {
+    let sha256: [u8; 32] = sha256
+        .try_into()
+        .map_err(|_| "sha256 must be exactly 32 bytes".to_string())?;
+    if total_len == 0 {
+        return Err("knowledge base must not be empty".to_string());
+    }
+    if total_len > MAX_LEN {
+        return Err(format!(
+            "knowledge base must not be larger than {} bytes",
+            MAX_LEN
+        ));
+    }
+    UPLOAD.with_borrow_mut(|upload| {
+        *upload = Some(Upload {
+            total_len,
+            sha256,
+            bytes: Vec::with_capacity(total_len as usize),
+        });
+    });
+    Ok(())
+}
+
+/// Appends the next chunk of the knowledge base to the upload in progress.
+pub fn append(chunk: Vec<u8>) -> Result<(), String> {
+    UPLOAD.with_borrow_mut(|upload| {
+        let upload = upload
+            .as_mut()
+            .ok_or_else(|| "no knowledge base upload in progress".to_string())?;
+        if (upload.bytes.len() + chunk.len()) as u64 > upload.total_len {
+            return Err(format!(
+                "chunk exceeds the declared knowledge base size of {} bytes",
+                upload.total_len
+            ));
+        }
+        upload.bytes.extend_from_slice(&chunk);
+        Ok(())
+    })
+}
+
+/// Verifies and validates the uploaded knowledge base and replaces the
+/// current one with it.
+pub fn commit() -> Result<(), String> {
+    let upload = UPLOAD
+        .with_borrow_mut(|upload| upload.take())
+        .ok_or_else(|| "no knowledge base upload in progress".to_string())?;
+    if upload.bytes.len() as u64 != upload.total_len {
+        return Err(format!(
+            "received {} of {} bytes",
+            upload.bytes.len(),
+            upload.total_len
+        ));
+    }
+    if Sha256::digest(&upload.bytes).as_slice() != upload.sha256 {
+        return Err("sha256 of the uploaded knowledge base does not match".to_string());
+    }
+    let knowledge_base = parse(&upload.bytes)?;
+    let current = info().version;
+    if knowledge_base.version <= current {
+        return Err(format!(
+            "knowledge base version {} is not newer than the current version {}",
+            knowledge_base.version, current
+        ));
+    }
+
+    STORED.with_borrow_mut(|stored| stored.set(upload.bytes).unwrap());
+    KNOWLEDGE_BASE.with_borrow_mut(|current| *current = Some(knowledge_base));
+    Ok(())
+}
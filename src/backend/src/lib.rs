@@ -1,31 +1,369 @@
-use candid::{CandidType, Deserialize};
+use candid::{CandidType, Deserialize, Principal};
 use ic_stable_structures::{
-    memory_manager::{MemoryId, MemoryManager},
+    memory_manager::{MemoryId, MemoryManager, VirtualMemory},
     DefaultMemoryImpl,
 };
+use serde::Serialize;
 use std::cell::RefCell;
 
+mod cache;
+mod credentials;
+mod descriptions;
+mod history;
+mod http;
+mod knowledge_base;
+mod llm;
+mod lost_found;
+mod names;
 mod onnx;
+mod pets;
+mod preprocess;
+mod principal_key;
+mod species;
+mod taxonomy;
+mod upload;
+mod vector_index;
+
+type Memory = VirtualMemory<DefaultMemoryImpl>;
 
 // WASI polyfill requires a virtual stable memory to store the file system.
 // You can replace `0` with any index up to `254`.
 const WASI_MEMORY_ID: MemoryId = MemoryId::new(0);
 
+// Staging area for the ONNX model uploaded with `begin_model_upload` and friends.
+const MODEL_MEMORY_ID: MemoryId = MemoryId::new(1);
+
+// Committed models keyed by their id.
+const MODELS_MEMORY_ID: MemoryId = MemoryId::new(2);
+
+// API keys of the LLM providers, set with `set_llm_credentials`.
+const CREDENTIALS_MEMORY_ID: MemoryId = MemoryId::new(3);
+
+// The `LlmConfig` set with `set_llm_config`.
+const LLM_CONFIG_MEMORY_ID: MemoryId = MemoryId::new(4);
+
+// Descriptions generated by `describe`, keyed by prompt version, language and label.
+const DESCRIPTIONS_MEMORY_ID: MemoryId = MemoryId::new(5);
+
+// The knowledge base uploaded with `begin_knowledge_base_upload` and friends.
+const KNOWLEDGE_BASE_MEMORY_ID: MemoryId = MemoryId::new(6);
+
+// The classification history of every principal, see `my_history`.
+const HISTORY_MEMORY_ID: MemoryId = MemoryId::new(7);
+
+// The pets registered with `register_pet`.
+const PETS_MEMORY_ID: MemoryId = MemoryId::new(8);
+
+// The similarity index of `index_image`: the entries, the list of every entry
+// and the centers of the lists.
+const INDEX_ENTRIES_MEMORY_ID: MemoryId = MemoryId::new(9);
+const INDEX_LOCATIONS_MEMORY_ID: MemoryId = MemoryId::new(10);
+const INDEX_CENTROIDS_MEMORY_ID: MemoryId = MemoryId::new(11);
+
+// The reports of the lost and found board and the inboxes of the reporters.
+const REPORTS_MEMORY_ID: MemoryId = MemoryId::new(12);
+const INBOX_MEMORY_ID: MemoryId = MemoryId::new(13);
+
+// The id that `register_pet` gives to the next pet of every owner.
+const PET_IDS_MEMORY_ID: MemoryId = MemoryId::new(14);
+
+// The keys of the cached descriptions in the order they were created.
+const DESCRIPTION_AGES_MEMORY_ID: MemoryId = MemoryId::new(15);
+
+// The profiles written by `species_profile` and their keys in the order they
+// were created.
+const SPECIES_PROFILES_MEMORY_ID: MemoryId = MemoryId::new(16);
+const SPECIES_PROFILE_AGES_MEMORY_ID: MemoryId = MemoryId::new(17);
+
+// The images of every principal in the similarity index and the rebuild of
+// the index in progress.
+const INDEX_OWNERS_MEMORY_ID: MemoryId = MemoryId::new(18);
+const INDEX_REBUILD_MEMORY_ID: MemoryId = MemoryId::new(19);
+
+// The reports of the lost and found board by kind and by owner.
+const REPORT_CANDIDATES_MEMORY_ID: MemoryId = MemoryId::new(20);
+const REPORT_OWNERS_MEMORY_ID: MemoryId = MemoryId::new(21);
+
 thread_local! {
     // The memory manager is used for simulating multiple memories.
     static MEMORY_MANAGER: RefCell<MemoryManager<DefaultMemoryImpl>> =
         RefCell::new(MemoryManager::init(DefaultMemoryImpl::default()));
 }
 
-#[derive(CandidType, Deserialize)]
+#[derive(CandidType, Deserialize, Serialize, Clone)]
 struct Classification {
     label: String,
+    /// The probability of the label in [0, 1].
     score: f32,
+    /// The unnormalised output of the model, kept for debugging.
+    raw_logit: f32,
+    /// The WordNet synset of the label, if the model provides one.
+    synset: Option<String>,
+    /// The binomial name of the species, or the genus or family if the label
+    /// covers several species.
+    scientific_name: Option<String>,
+}
+
+/// A taxonomic rank that classification results can be rolled up to.
+#[derive(CandidType, Deserialize, Clone, Copy, PartialEq)]
+enum Rank {
+    /// The class of the model itself, e.g. a dog breed.
+    Label,
+    Species,
+    Family,
+    Order,
+    Class,
+}
+
+/// How an image that is not square is fitted to the input of the model.
+#[derive(CandidType, Deserialize, Clone, Copy, PartialEq)]
+enum Crop {
+    /// Resize the shorter side and cut a square out of the center, like the
+    /// reference preprocessing of MobileNet. This is the default, except for
+    /// the endpoints that predate `ClassifyOptions`.
+    Center,
+    /// Squash the whole image into a square.
+    Stretch,
+}
+
+/// The taxa an animal label belongs to. Ranks that are not known are empty.
+#[derive(CandidType, Deserialize, Serialize)]
+struct Lineage {
+    species: String,
+    family: Option<String>,
+    order: Option<String>,
+    class: Option<String>,
+}
+
+/// The common name of a label in a locale such as `en` or `id`.
+#[derive(CandidType, Deserialize, Serialize)]
+struct CommonName {
+    locale: String,
+    name: String,
+}
+
+#[derive(CandidType, Deserialize, Serialize)]
+struct LabelInfo {
+    index: u32,
+    label: String,
+    synset: Option<String>,
+    lineage: Option<Lineage>,
+    scientific_name: Option<String>,
+    common_names: Vec<CommonName>,
+}
+
+/// Describes the input and output of an image classification model.
+#[derive(CandidType, Deserialize, Clone)]
+struct ModelConfig {
+    /// The label of every output of the model, in order.
+    labels: Vec<String>,
+    /// The model accepts a square RGB image of this many pixels per side.
+    input_size: u32,
+    /// Per-channel mean and standard deviation used to normalise the input.
+    mean: Vec<f32>,
+    std: Vec<f32>,
+    /// Softmax temperature used to calibrate the probabilities. Defaults to 1.
+    temperature: Option<f32>,
+    /// The indices of the labels that are animals. If not set, every label
+    /// is considered an animal.
+    animal_classes: Option<Vec<u32>>,
+    /// The WordNet synset of every label, which links it to the taxonomy.
+    synsets: Option<Vec<String>>,
+    /// The name of the tensor that `embed` returns. Defaults to the input of
+    /// the last fully connected or 1x1 convolution layer.
+    embedding_output: Option<String>,
+}
+
+impl ModelConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.labels.is_empty() {
+            return Err("labels must not be empty".to_string());
+        }
+        if self.input_size == 0 {
+            return Err("input_size must be positive".to_string());
+        }
+        if self.mean.len() != 3 || self.std.len() != 3 {
+            return Err("mean and std must have one value per RGB channel".to_string());
+        }
+        if self.std.iter().any(|std| *std == 0.0) {
+            return Err("std must not contain zeros".to_string());
+        }
+        if self.temperature.is_some_and(|temperature| temperature <= 0.0) {
+            return Err("temperature must be positive".to_string());
+        }
+        if let Some(animal_classes) = &self.animal_classes {
+            if animal_classes
+                .iter()
+                .any(|&index| index as usize >= self.labels.len())
+            {
+                return Err("animal_classes must be indices into labels".to_string());
+            }
+        }
+        if self
+            .synsets
+            .as_ref()
+            .is_some_and(|synsets| synsets.len() != self.labels.len())
+        {
+            return Err("synsets must have one entry per label".to_string());
+        }
+        if self
+            .embedding_output
+            .as_ref()
+            .is_some_and(|name| name.is_empty())
+        {
+            return Err("embedding_output must not be empty".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[derive(CandidType, Deserialize, Serialize)]
+struct ModelInfo {
+    id: String,
+    label_count: u32,
+    input_size: u32,
+    /// Whether the model can be used with `embed`.
+    embeds: bool,
+}
+
+/// Controls which labels the `*_v2` classify endpoints return.
+#[derive(CandidType, Deserialize, Default)]
+struct ClassifyOptions {
+    /// The model to use instead of the default one.
+    model_id: Option<String>,
+    /// The maximum number of labels to return.
+    top_k: Option<u32>,
+    /// Labels with a lower probability are dropped.
+    min_score: Option<f32>,
+    /// If set, only these labels are returned.
+    include_labels: Option<Vec<String>>,
+    /// These labels are never returned.
+    exclude_labels: Option<Vec<String>>,
+    /// Only return animals and report images that are not of an animal.
+    animals_only: Option<bool>,
+    /// Sum the probabilities of labels up to this taxonomic rank.
+    rollup: Option<Rank>,
+    /// Return common names in this locale, e.g. `id` or `es-MX`, instead of
+    /// English. Every label is translated to `id`, `es` and `fr`, but taxa
+    /// rolled up with `rollup` keep their English names. The label filters
+    /// always match the English names.
+    locale: Option<String>,
+    /// How to fit the image to the input of the model.
+    crop: Option<Crop>,
+    /// Keep a small thumbnail of the image in the history of the caller.
+    thumbnail: Option<bool>,
+}
+
+impl ClassifyOptions {
+    /// Whether the label passes the include and exclude filters.
+    fn accepts(&self, label: &str) -> bool {
+        let included = self
+            .include_labels
+            .as_ref()
+            .map_or(true, |labels| labels.iter().any(|l| l == label));
+        let excluded = self
+            .exclude_labels
+            .as_ref()
+            .is_some_and(|labels| labels.iter().any(|l| l == label));
+        included && !excluded
+    }
 }
 
+/// The image is not in one of the formats that can be decoded.
+#[derive(CandidType, Deserialize, Serialize)]
+struct UnsupportedFormat {
+    /// The MIME type of the image, if it could be recognised at all.
+    format: Option<String>,
+    /// The MIME types of the formats that are accepted.
+    accepted: Vec<String>,
+}
+
+impl std::fmt::Display for UnsupportedFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "unsupported image format {}, expected one of {}",
+            self.format.as_deref().unwrap_or("unknown"),
+            self.accepted.join(", ")
+        )
+    }
+}
+
+/// Why an image could not be classified.
+#[derive(CandidType, Deserialize, Serialize)]
+enum ClassifyError {
+    /// No model is registered under the requested id.
+    ModelNotLoaded { model_id: String },
+    /// The image is in a supported format but could not be decoded.
+    DecodeFailed { message: String },
+    /// The image has more pixels than can be processed in a single call.
+    ImageTooLarge {
+        width: u32,
+        height: u32,
+        max_pixels: u64,
+    },
+    /// The shorter side of the image is too small to classify.
+    ImageTooSmall {
+        width: u32,
+        height: u32,
+        min_side: u32,
+    },
+    UnsupportedFormat(UnsupportedFormat),
+    /// The model failed to run on the image.
+    InferenceFailed { message: String },
+    /// The request does not fit the model, e.g. breeds of a pet the model
+    /// does not know.
+    InvalidRequest { message: String },
+    /// The caller, or the canister as a whole, has reached a limit, e.g. the
+    /// number of pets an owner can register.
+    QuotaExceeded { quota: String, limit: u64 },
+}
+
+impl std::fmt::Display for ClassifyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ClassifyError::ModelNotLoaded { model_id } => {
+                write!(f, "model {} is not loaded", model_id)
+            }
+            ClassifyError::DecodeFailed { message } => {
+                write!(f, "failed to decode the image: {}", message)
+            }
+            ClassifyError::ImageTooLarge {
+                width,
+                height,
+                max_pixels,
+            } => write!(
+                f,
+                "image of {}x{} pixels exceeds the limit of {} pixels",
+                width, height, max_pixels
+            ),
+            ClassifyError::ImageTooSmall {
+                width,
+                height,
+                min_side,
+            } => write!(
+                f,
+                "image of {}x{} pixels is smaller than {} pixels per side",
+                width, height, min_side
+            ),
+            ClassifyError::UnsupportedFormat(unsupported) => write!(f, "{}", unsupported),
+            ClassifyError::InferenceFailed { message } => {
+                write!(f, "failed to run the model: {}", message)
+            }
+            ClassifyError::InvalidRequest { message } => f.write_str(message),
+            ClassifyError::QuotaExceeded { quota, limit } => {
+                write!(f, "the limit of {} {} is reached", limit, quota)
+            }
+        }
+    }
+}
+
+/// The error of the endpoints that predate `ClassifyError`.
 #[derive(CandidType, Deserialize)]
 struct ClassificationError {
     message: String,
+    /// The same error as a variant that clients can match on.
+    kind: Option<ClassifyError>,
 }
 
 #[derive(CandidType, Deserialize)]
@@ -34,27 +372,900 @@ enum ClassificationResult {
     Err(ClassificationError),
 }
 
-#[ic_cdk::query]
-fn classify(image: Vec<u8>) -> ClassificationResult {
-    let result = match onnx::classify(image) {
-        Ok(result) => ClassificationResult::Ok(result),
-        Err(err) => ClassificationResult::Err(ClassificationError {
+/// Returned instead of labels when animals were requested but the image
+/// most likely does not show one.
+#[derive(CandidType, Deserialize, Serialize)]
+struct NotAnimal {
+    /// The combined probability of all animal labels.
+    animal_score: f32,
+    /// The most probable label that is not an animal.
+    top_label: Classification,
+}
+
+#[derive(CandidType, Deserialize, Serialize)]
+enum ClassificationResultV2 {
+    Ok(Vec<Classification>),
+    NotAnimal(NotAnimal),
+    Err(ClassifyError),
+}
+
+/// A classification made by `classify_v2` or `classify_with`.
+#[derive(CandidType, Deserialize)]
+struct HistoryEntry {
+    /// Identifies the entry among the entries of its principal.
+    id: u64,
+    /// Nanoseconds since the epoch.
+    timestamp: u64,
+    model_id: String,
+    /// The most probable labels as they were returned.
+    results: Vec<Classification>,
+    /// Set if the image did not look like an animal, to the combined
+    /// probability of the animal labels. `results` then only holds the most
+    /// probable label that is not an animal.
+    animal_score: Option<f32>,
+    image_sha256: Vec<u8>,
+    /// A JPEG of 64 by 64 pixels, if `thumbnail` was set in the options.
+    thumbnail: Option<Vec<u8>>,
+}
+
+#[derive(CandidType, Deserialize)]
+struct HistoryPage {
+    /// Newest first.
+    entries: Vec<HistoryEntry>,
+    /// Pass as `cursor` to get the next page. Not set on the last page.
+    next: Option<u64>,
+}
+
+type HeaderField = (String, String);
+
+#[derive(CandidType, Deserialize)]
+struct HttpRequest {
+    method: String,
+    url: String,
+    headers: Vec<HeaderField>,
+    body: Vec<u8>,
+}
+
+#[derive(CandidType, Deserialize)]
+struct HttpResponse {
+    status_code: u16,
+    headers: Vec<HeaderField>,
+    body: Vec<u8>,
+    /// Asks the HTTP gateway to repeat the request as an update call.
+    upgrade: Option<bool>,
+}
+
+/// Tokens consumed by a completion, as reported by the provider.
+#[derive(CandidType, Deserialize, Clone)]
+struct LlmUsage {
+    prompt_tokens: u32,
+    completion_tokens: u32,
+    total_tokens: u32,
+}
+
+#[derive(CandidType, Deserialize, Clone)]
+struct LlmResponse {
+    /// The completion of the prompt.
+    text: String,
+    /// The model that produced the completion.
+    model: String,
+    usage: Option<LlmUsage>,
+}
+
+#[derive(CandidType, Deserialize)]
+enum LlmErrorKind {
+    /// No API key was set with `set_llm_credentials`.
+    NotConfigured,
+    /// The HTTPS outcall itself failed.
+    OutcallFailed,
+    /// The provider answered with an error status.
+    ProviderError,
+    /// The provider answered with a body that is not a completion.
+    InvalidResponse,
+    /// The request was rejected without an outcall, e.g. an unknown label.
+    InvalidRequest,
+}
+
+#[derive(CandidType, Deserialize)]
+struct LlmError {
+    kind: LlmErrorKind,
+    /// The HTTP status of the response, if there was one.
+    status: Option<u16>,
+    message: String,
+}
+
+#[derive(CandidType, Deserialize)]
+enum LlmResult {
+    Ok(LlmResponse),
+    Err(LlmError),
+}
+
+/// A fact sheet about an animal, written by the LLM.
+#[derive(CandidType, Deserialize, Clone)]
+struct SpeciesProfile {
+    label: String,
+    summary: String,
+    habitat: String,
+    diet: String,
+    /// The typical lifespan, e.g. "10 to 12 years".
+    lifespan: String,
+    temperament: String,
+    care_tips: Vec<String>,
+    /// The IUCN Red List category of wild species. Not set for domestic
+    /// animals.
+    conservation_status: Option<String>,
+    source: ProfileSource,
+}
+
+/// Where the facts of a `SpeciesProfile` come from.
+#[derive(CandidType, Deserialize, Clone)]
+enum ProfileSource {
+    Llm,
+    /// The knowledge base, used when the LLM is not configured or fails.
+    /// `taxon` is the label, species, family, order or class whose facts
+    /// were found, so the facts can be more general than the label.
+    KnowledgeBase { version: u32, taxon: String },
+}
+
+#[derive(CandidType, Deserialize)]
+struct KnowledgeBaseInfo {
+    version: u32,
+    entries: u32,
+    /// Whether the knowledge base compiled into the wasm is used, because
+    /// none was uploaded or the uploaded one failed to load.
+    embedded: bool,
+}
+
+#[derive(CandidType, Deserialize)]
+enum SpeciesProfileResult {
+    Ok(SpeciesProfile),
+    Err(LlmError),
+}
+
+/// The API that `llm` sends prompts to.
+#[derive(CandidType, Deserialize, Clone, Copy, PartialEq)]
+enum LlmProvider {
+    OpenAi,
+    Anthropic,
+    /// A server that implements the chat completions API of OpenAI, such as
+    /// a local llama.cpp, vLLM or Ollama server.
+    OpenAiCompatible,
+}
+
+impl LlmProvider {
+    /// The name under which the API key of the provider is stored.
+    fn id(self) -> &'static str {
+        match self {
+            LlmProvider::OpenAi => "openai",
+            LlmProvider::Anthropic => "anthropic",
+            LlmProvider::OpenAiCompatible => "openai_compatible",
+        }
+    }
+
+    /// The highest sampling temperature the API accepts.
+    fn max_temperature(self) -> f32 {
+        match self {
+            LlmProvider::Anthropic => 1.0,
+            LlmProvider::OpenAi | LlmProvider::OpenAiCompatible => 2.0,
+        }
+    }
+}
+
+/// Selects the provider and model used by `llm`.
+#[derive(CandidType, Deserialize, Clone)]
+struct LlmConfig {
+    provider: LlmProvider,
+    /// The URL the API paths are appended to, e.g. `http://localhost:8080/v1`.
+    /// Defaults to the public API of the provider and is required for
+    /// `OpenAiCompatible`.
+    base_url: Option<String>,
+    model: String,
+    /// The maximum number of tokens of the completion. Anthropic requires a
+    /// limit and defaults to 1024.
+    max_tokens: Option<u32>,
+    /// The sampling temperature in [0, 2], or [0, 1] for Anthropic. Defaults
+    /// to the provider's. The
+    /// replicas only agree on the completion if it is deterministic, so most
+    /// providers need 0.
+    temperature: Option<f32>,
+}
+
+impl Default for LlmConfig {
+    fn default() -> Self {
+        Self {
+            provider: LlmProvider::OpenAi,
+            base_url: None,
+            model: "gpt-3.5-turbo".to_string(),
+            max_tokens: None,
+            temperature: None,
+        }
+    }
+}
+
+impl LlmConfig {
+    fn validate(&self) -> Result<(), String> {
+        match &self.base_url {
+            Some(base_url) => {
+                if !base_url.starts_with("https://") && !base_url.starts_with("http://") {
+                    return Err("base_url must be an http or https URL".to_string());
+                }
+            }
+            None if self.provider == LlmProvider::OpenAiCompatible => {
+                return Err("base_url is required for OpenAiCompatible".to_string());
+            }
+            None => {}
+        }
+        if self.model.trim().is_empty() {
+            return Err("model must not be empty".to_string());
+        }
+        if self.max_tokens == Some(0) {
+            return Err("max_tokens must be positive".to_string());
+        }
+        let max_temperature = self.provider.max_temperature();
+        if self
+            .temperature
+            .is_some_and(|temperature| !(0.0..=max_temperature).contains(&temperature))
+        {
+            return Err(format!("temperature must be between 0 and {}", max_temperature));
+        }
+        Ok(())
+    }
+}
+
+/// A pet whose breed can be estimated with `breed_mix`.
+#[derive(CandidType, Deserialize, Clone, Copy)]
+enum Pet {
+    Dog,
+    Cat,
+}
+
+impl Pet {
+    /// The species of the pet as named in the taxonomy.
+    fn species(self) -> &'static str {
+        match self {
+            Pet::Dog => "dog",
+            Pet::Cat => "cat",
+        }
+    }
+}
+
+#[derive(CandidType, Deserialize)]
+struct BreedShare {
+    label: String,
+    /// The share of the breed in the mix, in percent.
+    percentage: f32,
+    synset: Option<String>,
+}
+
+#[derive(CandidType, Deserialize)]
+struct BreedMix {
+    /// The breeds that contribute the most, in descending order.
+    breeds: Vec<BreedShare>,
+    /// The combined probability of all breeds of the pet, i.e. how likely the
+    /// image shows that kind of pet at all.
+    pet_score: f32,
+    /// The entropy of the breed distribution normalised to [0, 1].
+    entropy: f32,
+    /// Whether the breeds are too evenly spread for a purebred.
+    mixed_breed_likely: bool,
+}
+
+#[derive(CandidType, Deserialize)]
+enum BreedMixResult {
+    Ok(BreedMix),
+    Err(ClassifyError),
+}
+
+/// How `embed` scales the embedding.
+#[derive(CandidType, Deserialize, Clone, Copy, PartialEq)]
+enum Normalization {
+    /// The features as the model computes them.
+    Raw,
+    /// Scaled to unit length, so that the dot product of two embeddings is
+    /// their cosine similarity. This is the default.
+    L2,
+}
+
+#[derive(CandidType, Deserialize, Default)]
+struct EmbedOptions {
+    /// The model to use. Defaults to `mobilenetv2`.
+    model_id: Option<String>,
+    /// How to fit the image to the input of the model.
+    crop: Option<Crop>,
+    normalization: Option<Normalization>,
+}
+
+#[derive(CandidType, Deserialize)]
+enum EmbedResult {
+    Ok(Vec<f32>),
+    Err(ClassifyError),
+}
+
+#[derive(CandidType, Deserialize)]
+enum IndexImageResult {
+    Ok(u64),
+    Err(ClassifyError),
+}
+
+#[derive(CandidType, Deserialize)]
+struct SimilarImage {
+    /// The id returned by `index_image`.
+    id: u64,
+    /// The cosine similarity of the embeddings, in [-1, 1].
+    similarity: f32,
+    /// The principal that indexed the image.
+    owner: Principal,
+    /// Nanoseconds since the epoch.
+    indexed_at: u64,
+}
+
+#[derive(CandidType, Deserialize)]
+enum SearchSimilarResult {
+    Ok(Vec<SimilarImage>),
+    Err(ClassifyError),
+}
+
+#[derive(CandidType, Deserialize)]
+struct SimilarityIndexInfo {
+    entries: u64,
+    /// The number of clusters searches choose from. Zero until the index has
+    /// enough images, when searches compare every image.
+    lists: u32,
+    /// Whether the images are being moved to new clusters, which takes a
+    /// message per 1000 images. Searches compare every image meanwhile.
+    rebuilding: bool,
+}
+
+#[derive(CandidType, Deserialize, Clone, Copy, PartialEq)]
+enum ReportKind {
+    /// Posted by the owner of a missing animal.
+    Lost,
+    /// Posted by someone who found an animal.
+    Found,
+}
+
+#[derive(CandidType, Deserialize)]
+struct NewReport {
+    kind: ReportKind,
+    /// Up to three photos of the animal.
+    photos: Vec<Vec<u8>>,
+    /// Where the animal was last seen or found.
+    area: String,
+    /// How to reach the reporter, such as a phone number or a handle.
+    contact: String,
+}
+
+#[derive(CandidType, Deserialize)]
+enum PostReportResult {
+    Ok(u64),
+    Err(ClassifyError),
+}
+
+/// A report on the lost and found board.
+#[derive(CandidType, Deserialize)]
+struct Report {
+    id: u64,
+    kind: ReportKind,
+    owner: Principal,
+    /// Nanoseconds since the epoch.
+    created_at: u64,
+    area: String,
+    contact: String,
+    /// The most probable breeds, averaged over the photos.
+    breeds: Vec<Classification>,
+    /// A JPEG of 64 by 64 pixels of every photo.
+    thumbnails: Vec<Vec<u8>>,
+}
+
+/// How well a report of the other kind matches a report.
+#[derive(CandidType, Deserialize, Clone)]
+struct ReportMatch {
+    report_id: u64,
+    /// Weighs `similarity` and `breed_agreement`.
+    score: f32,
+    /// The cosine similarity of the most similar photos, in [-1, 1].
+    similarity: f32,
+    /// The probability the breeds of both reports agree on, in [0, 1].
+    breed_agreement: f32,
+}
+
+/// Tells a reporter about a likely match of one of their reports.
+#[derive(CandidType, Deserialize)]
+struct Notification {
+    id: u64,
+    /// Nanoseconds since the epoch.
+    created_at: u64,
+    /// The report of the recipient.
+    report_id: u64,
+    candidate: ReportMatch,
+}
+
+#[derive(CandidType, Deserialize)]
+struct InboxPage {
+    /// Oldest first.
+    notifications: Vec<Notification>,
+    /// Pass as `cursor` to get the notifications that follow, including the
+    /// ones that have not arrived yet.
+    next: Option<u64>,
+    /// Whether more notifications follow right away.
+    more: bool,
+}
+
+/// A pet registered with `register_pet`.
+#[derive(CandidType, Deserialize)]
+struct PetInfo {
+    id: u64,
+    name: String,
+    /// The number of photos the pet was registered with.
+    photos: u32,
+    /// Nanoseconds since the epoch.
+    registered_at: u64,
+}
+
+#[derive(CandidType, Deserialize, Clone)]
+struct PetMatch {
+    pet_id: u64,
+    name: String,
+    /// The cosine similarity of the image to the most similar photo of the
+    /// pet, in [-1, 1].
+    similarity: f32,
+}
+
+#[derive(CandidType, Deserialize)]
+struct PetIdentification {
+    /// Every registered pet of the caller, most similar first.
+    candidates: Vec<PetMatch>,
+    /// The pet in the image. Not set unless the most similar pet is similar
+    /// enough and clearly more similar than the others.
+    pet: Option<PetMatch>,
+}
+
+#[derive(CandidType, Deserialize)]
+enum RegisterPetResult {
+    Ok(u64),
+    Err(ClassifyError),
+}
+
+#[derive(CandidType, Deserialize)]
+enum IdentifyPetResult {
+    Ok(PetIdentification),
+    Err(ClassifyError),
+}
+
+/// Runs the classifier and wraps its output in a `ClassificationResult`.
+fn classify_image(image: Vec<u8>, options: ClassifyOptions) -> ClassificationResult {
+    legacy_result(classify_image_v2(image, options))
+}
+
+/// Converts the result for the endpoints that predate `ClassificationResultV2`.
+fn legacy_result(result: ClassificationResultV2) -> ClassificationResult {
+    match result {
+        ClassificationResultV2::Ok(result) => ClassificationResult::Ok(result),
+        ClassificationResultV2::NotAnimal(not_animal) => {
+            ClassificationResult::Ok(vec![not_animal.top_label])
+        }
+        ClassificationResultV2::Err(err) => ClassificationResult::Err(ClassificationError {
             message: err.to_string(),
+            kind: Some(err),
         }),
-    };
+    }
+}
+
+/// Runs the classifier and wraps its output in a `ClassificationResultV2`.
+fn classify_image_v2(image: Vec<u8>, options: ClassifyOptions) -> ClassificationResultV2 {
+    match onnx::classify(image, &options) {
+        Ok(onnx::Verdict::Labels(result)) => ClassificationResultV2::Ok(result),
+        Ok(onnx::Verdict::NotAnimal(not_animal)) => ClassificationResultV2::NotAnimal(not_animal),
+        Err(err) => ClassificationResultV2::Err(err),
+    }
+}
+
+/// Classifies the image and adds the labels, or the label of an image that
+/// is not an animal, to the history of the caller. Anonymous callers have no
+/// history.
+fn classify_and_record(image: Vec<u8>, options: ClassifyOptions) -> ClassificationResultV2 {
+    let draft = history::draft(ic_cdk::api::caller(), &image, &options);
+    let result = classify_image_v2(image, options);
+    if let Some(draft) = draft {
+        match &result {
+            ClassificationResultV2::Ok(labels) => history::record(draft, labels, None),
+            ClassificationResultV2::NotAnimal(not_animal) => history::record(
+                draft,
+                std::slice::from_ref(&not_animal.top_label),
+                Some(not_animal.animal_score),
+            ),
+            ClassificationResultV2::Err(_) => {}
+        }
+    }
     result
 }
 
+/// The options of the endpoints that predate `ClassifyOptions`: only the
+/// single most probable label of the default model, with the image squashed
+/// into a square as these endpoints always did.
+fn legacy_options() -> ClassifyOptions {
+    ClassifyOptions {
+        top_k: Some(1),
+        crop: Some(Crop::Stretch),
+        ..Default::default()
+    }
+}
+
+#[ic_cdk::query]
+fn classify(image: Vec<u8>) -> ClassificationResult {
+    classify_image(image, legacy_options())
+}
+
 #[ic_cdk::query]
 fn classify_query(image: Vec<u8>) -> ClassificationResult {
-    let result = match onnx::classify(image) {
-        Ok(result) => ClassificationResult::Ok(result),
-        Err(err) => ClassificationResult::Err(ClassificationError {
-            message: err.to_string(),
-        }),
-    };
-    result
+    classify_image(image, legacy_options())
+}
+
+/// Classifies the image with the model registered under `model_id`.
+#[ic_cdk::update]
+fn classify_with(model_id: String, image: Vec<u8>) -> ClassificationResult {
+    legacy_result(classify_and_record(
+        image,
+        ClassifyOptions {
+            model_id: Some(model_id),
+            ..legacy_options()
+        },
+    ))
+}
+
+#[ic_cdk::update]
+fn classify_v2(image: Vec<u8>, options: ClassifyOptions) -> ClassificationResultV2 {
+    classify_and_record(image, options)
+}
+
+#[ic_cdk::query]
+fn classify_query_v2(image: Vec<u8>, options: ClassifyOptions) -> ClassificationResultV2 {
+    classify_image_v2(image, options)
+}
+
+/// Estimates the mix of breeds of the dog or cat in the image. Of the options
+/// only `model_id`, `top_k`, `min_score` and `locale` are used, and `min_score`
+/// applies to the share of a breed rather than its probability.
+#[ic_cdk::query]
+fn breed_mix(image: Vec<u8>, pet: Pet, options: ClassifyOptions) -> BreedMixResult {
+    match onnx::breed_mix(image, pet, &options) {
+        Ok(mix) => BreedMixResult::Ok(mix),
+        Err(err) => BreedMixResult::Err(err),
+    }
+}
+
+#[ic_cdk::query]
+fn list_models() -> Vec<ModelInfo> {
+    onnx::models()
+}
+
+/// Describes the labels of a model, defaulting to the default model.
+#[ic_cdk::query]
+fn list_labels(model_id: Option<String>) -> Result<Vec<LabelInfo>, String> {
+    onnx::labels(model_id.as_deref().unwrap_or(onnx::DEFAULT_MODEL_ID))
+}
+
+/// Serves the REST API of the canister through the HTTP gateway.
+#[ic_cdk::query]
+fn http_request(request: HttpRequest) -> HttpResponse {
+    http::handle(request, false)
+}
+
+/// Serves the requests that `http_request` upgraded to an update call.
+#[ic_cdk::update]
+fn http_request_update(request: HttpRequest) -> HttpResponse {
+    http::handle(request, true)
+}
+
+/// Returns a page of the classifications of the caller, newest first.
+#[ic_cdk::query]
+fn my_history(cursor: Option<u64>, limit: Option<u32>) -> HistoryPage {
+    history::page(ic_cdk::api::caller(), cursor, limit)
+}
+
+#[ic_cdk::update]
+fn delete_history_entry(id: u64) -> Result<(), String> {
+    if history::delete(ic_cdk::api::caller(), id) {
+        Ok(())
+    } else {
+        Err(format!("history entry {} does not exist", id))
+    }
+}
+
+/// Deletes the whole history of the caller and returns the number of entries.
+#[ic_cdk::update]
+fn clear_my_history() -> u64 {
+    history::clear(ic_cdk::api::caller())
+}
+
+/// Returns the features of the image that the classifier layer of the model
+/// is applied to, such as the 1280 pooled features of MobileNet. Images with
+/// similar content have similar embeddings.
+#[ic_cdk::query]
+fn embed(image: Vec<u8>, options: EmbedOptions) -> EmbedResult {
+    match onnx::embed(&image, &options) {
+        Ok(embedding) => EmbedResult::Ok(embedding),
+        Err(err) => EmbedResult::Err(err),
+    }
+}
+
+/// Adds the embedding of the image to the similarity index and returns its id.
+#[ic_cdk::update]
+fn index_image(image: Vec<u8>) -> IndexImageResult {
+    match vector_index::insert(ic_cdk::api::caller(), &image) {
+        Ok(id) => IndexImageResult::Ok(id),
+        Err(err) => IndexImageResult::Err(err),
+    }
+}
+
+/// Returns the `k` indexed images most similar to the image. The search is
+/// approximate once the index is large enough to be clustered.
+#[ic_cdk::query]
+fn search_similar(image: Vec<u8>, k: Option<u32>) -> SearchSimilarResult {
+    match vector_index::search(&image, k) {
+        Ok(images) => SearchSimilarResult::Ok(images),
+        Err(err) => SearchSimilarResult::Err(err),
+    }
+}
+
+/// Removes an image indexed by the caller. Controllers can remove any image.
+#[ic_cdk::update]
+fn remove_from_index(id: u64) -> Result<(), String> {
+    vector_index::remove(ic_cdk::api::caller(), id)
+}
+
+#[ic_cdk::query]
+fn similarity_index_info() -> SimilarityIndexInfo {
+    vector_index::info()
+}
+
+/// Clusters the indexed images again, which keeps searches accurate after
+/// many images were added since the index was last clustered. The images are
+/// moved to the new clusters in later messages.
+#[ic_cdk::update(guard = "caller_is_controller")]
+fn rebuild_similarity_index() -> SimilarityIndexInfo {
+    vector_index::rebuild();
+    vector_index::info()
+}
+
+/// Moves the next batch of images to the clusters of a rebuild. Called by
+/// the canister itself until every image has moved, and by controllers to
+/// resume a rebuild whose call failed.
+#[ic_cdk::update(guard = "caller_is_self_or_controller")]
+fn continue_similarity_index_rebuild() {
+    if vector_index::continue_rebuild() {
+        vector_index::schedule();
+    }
+}
+
+/// Posts a report to the lost and found board and returns its id. The
+/// reporters of the best matching reports of the other kind and the caller
+/// are notified in their inboxes.
+#[ic_cdk::update]
+fn post_report(report: NewReport) -> PostReportResult {
+    match lost_found::post(ic_cdk::api::caller(), report) {
+        Ok(id) => PostReportResult::Ok(id),
+        Err(err) => PostReportResult::Err(err),
+    }
+}
+
+#[ic_cdk::query]
+fn get_report(id: u64) -> Option<Report> {
+    lost_found::get(id)
+}
+
+#[ic_cdk::query]
+fn my_reports() -> Vec<Report> {
+    lost_found::list(ic_cdk::api::caller())
+}
+
+/// Ranks the reports of the other kind by how well they match the report.
+#[ic_cdk::query]
+fn report_matches(id: u64, k: Option<u32>) -> Result<Vec<ReportMatch>, String> {
+    lost_found::matches(id, k)
+}
+
+/// Deletes a report of the caller. Controllers can delete any report.
+#[ic_cdk::update]
+fn close_report(id: u64) -> Result<(), String> {
+    lost_found::close(ic_cdk::api::caller(), id)
+}
+
+/// Returns the notifications of the caller after `cursor`, oldest first.
+#[ic_cdk::query]
+fn my_inbox(cursor: Option<u64>, limit: Option<u32>) -> InboxPage {
+    lost_found::inbox(ic_cdk::api::caller(), cursor, limit)
+}
+
+/// Deletes the notifications of the caller up to and including `up_to`.
+#[ic_cdk::update]
+fn acknowledge_inbox(up_to: u64) -> u64 {
+    lost_found::acknowledge(ic_cdk::api::caller(), up_to)
+}
+
+/// Registers a pet of the caller from up to five photos of it and returns the
+/// id of the pet.
+#[ic_cdk::update]
+fn register_pet(name: String, photos: Vec<Vec<u8>>) -> RegisterPetResult {
+    match pets::register(ic_cdk::api::caller(), name, photos) {
+        Ok(id) => RegisterPetResult::Ok(id),
+        Err(err) => RegisterPetResult::Err(err),
+    }
+}
+
+/// Tells which of the pets of the caller is in the image, if any.
+#[ic_cdk::query]
+fn identify_my_pet(image: Vec<u8>) -> IdentifyPetResult {
+    match pets::identify(ic_cdk::api::caller(), &image) {
+        Ok(identification) => IdentifyPetResult::Ok(identification),
+        Err(err) => IdentifyPetResult::Err(err),
+    }
+}
+
+#[ic_cdk::query]
+fn my_pets() -> Vec<PetInfo> {
+    pets::list(ic_cdk::api::caller())
+}
+
+#[ic_cdk::update]
+fn remove_pet(id: u64) -> Result<(), String> {
+    if pets::remove(ic_cdk::api::caller(), id) {
+        Ok(())
+    } else {
+        Err(format!("pet {} does not exist", id))
+    }
+}
+
+fn caller_is_controller() -> Result<(), String> {
+    if ic_cdk::api::is_controller(&ic_cdk::api::caller()) {
+        Ok(())
+    } else {
+        Err("only controllers of the canister can call this method".to_string())
+    }
+}
+
+fn caller_is_self_or_controller() -> Result<(), String> {
+    if ic_cdk::api::caller() == ic_cdk::api::id() {
+        Ok(())
+    } else {
+        caller_is_controller()
+    }
+}
+
+/// Starts uploading a new ONNX model of `total_len` bytes with the given SHA-256
+/// that will be registered under `model_id`.
+#[ic_cdk::update(guard = "caller_is_controller")]
+fn begin_model_upload(
+    model_id: String,
+    config: ModelConfig,
+    total_len: u64,
+    sha256: Vec<u8>,
+) -> Result<(), String> {
+    upload::begin(model_id, config, total_len, sha256)
+}
+
+/// Appends the next chunk of the model that is being uploaded.
+#[ic_cdk::update(guard = "caller_is_controller")]
+fn append_model_chunk(chunk: Vec<u8>) -> Result<(), String> {
+    upload::append(chunk)
+}
+
+/// Verifies the uploaded model and registers it, replacing any model with
+/// the same id.
+#[ic_cdk::update(guard = "caller_is_controller")]
+fn commit_model_upload() -> Result<(), String> {
+    upload::commit(|id, bytes, config| {
+        onnx::load(id, bytes, config.clone()).map_err(|err| err.to_string())
+    })
+}
+
+/// Unregisters a model and deletes it from stable memory.
+#[ic_cdk::update(guard = "caller_is_controller")]
+fn remove_model(model_id: String) -> Result<(), String> {
+    upload::remove(&model_id);
+    if onnx::unload(&model_id) {
+        Ok(())
+    } else {
+        Err(format!("model {} is not loaded", model_id))
+    }
+}
+
+/// Stores the API key of a provider, replacing its previous key. Defaults to
+/// the provider selected with `set_llm_config`. The key is kept in stable
+/// memory and is never returned by any endpoint.
+#[ic_cdk::update(guard = "caller_is_controller")]
+fn set_llm_credentials(api_key: String, provider: Option<LlmProvider>) -> Result<(), String> {
+    let provider = provider.unwrap_or_else(|| llm::config().provider);
+    credentials::set(provider.id(), api_key)
+}
+
+/// Whether `llm` can authenticate with the provider selected with
+/// `set_llm_config`: an API key was set, or the provider does not need one.
+#[ic_cdk::query]
+fn has_llm_credentials() -> bool {
+    llm::has_credentials()
+}
+
+/// Selects the provider and model used by `llm`. Takes effect immediately
+/// and is kept across upgrades.
+#[ic_cdk::update(guard = "caller_is_controller")]
+fn set_llm_config(config: LlmConfig) -> Result<(), String> {
+    config.validate()?;
+    llm::set_config(config);
+    Ok(())
+}
+
+#[ic_cdk::query]
+fn get_llm_config() -> LlmConfig {
+    llm::config()
+}
+
+/// Describes the animal with the given label in the language of the locale.
+/// Descriptions are cached, so only the first call for a label and language
+/// makes an outcall.
+#[ic_cdk::update]
+async fn describe(label: String, locale: Option<String>) -> LlmResult {
+    descriptions::describe(label, locale).await
+}
+
+/// Returns the cached description of `describe`, if there is one that has
+/// not expired.
+#[ic_cdk::query]
+fn cached_description(label: String, locale: Option<String>) -> Option<LlmResponse> {
+    descriptions::get(&label, locale.as_deref())
+}
+
+/// Deletes the cached descriptions of the label, or all of them, and returns
+/// how many were deleted.
+#[ic_cdk::update(guard = "caller_is_controller")]
+fn invalidate_descriptions(label: Option<String>) -> u64 {
+    descriptions::invalidate(label.as_deref())
+}
+
+/// Asks the LLM for a fact sheet about the animal with the given label.
+/// Fact sheets are cached, so only the first call for a label makes an
+/// outcall.
+#[ic_cdk::update]
+async fn species_profile(label: String) -> SpeciesProfileResult {
+    species::profile(label).await
+}
+
+/// Deletes the cached fact sheet of the label, or all of them, and returns
+/// how many were deleted.
+#[ic_cdk::update(guard = "caller_is_controller")]
+fn invalidate_species_profiles(label: Option<String>) -> u64 {
+    species::invalidate(label.as_deref())
+}
+
+/// Returns the fact sheet of the animal from the knowledge base, without
+/// asking the LLM.
+#[ic_cdk::query]
+fn knowledge_base_profile(label: String) -> Option<SpeciesProfile> {
+    knowledge_base::profile(&label)
+}
+
+#[ic_cdk::query]
+fn knowledge_base_info() -> KnowledgeBaseInfo {
+    knowledge_base::info()
+}
+
+/// Starts uploading a knowledge base of `total_len` bytes of JSON with the
+/// given SHA-256. It replaces the current one when committed.
+#[ic_cdk::update(guard = "caller_is_controller")]
+fn begin_knowledge_base_upload(total_len: u64, sha256: Vec<u8>) -> Result<(), String> {
+    knowledge_base::begin(total_len, sha256)
+}
+
+#[ic_cdk::update(guard = "caller_is_controller")]
+fn append_knowledge_base_chunk(chunk: Vec<u8>) -> Result<(), String> {
+    knowledge_base::append(chunk)
+}
 
+/// Verifies and validates the uploaded knowledge base and replaces the
+/// current one, which must have a lower version.
+#[ic_cdk::update(guard = "caller_is_controller")]
+fn commit_knowledge_base_upload() -> Result<(), String> {
+    knowledge_base::commit()
 }
 
 #[ic_cdk::init]
@@ -62,6 +1273,7 @@ fn init() {
     let wasi_memory = MEMORY_MANAGER.with(|m| m.borrow().get(WASI_MEMORY_ID));
     ic_wasi_polyfill::init_with_memory(&[0u8; 32], &[], wasi_memory);
     onnx::setup().unwrap();
+    knowledge_base::setup();
 }
 
 #[ic_cdk::post_upgrade]
@@ -69,6 +1281,9 @@ fn post_upgrade() {
     let wasi_memory = MEMORY_MANAGER.with(|m| m.borrow().get(WASI_MEMORY_ID));
     ic_wasi_polyfill::init_with_memory(&[0u8; 32], &[], wasi_memory);
     onnx::setup().unwrap();
+    knowledge_base::setup();
+    vector_index::setup();
+    lost_found::setup();
 }
 
 const IMAGE: &'static [u8] = include_bytes!("../assets/man_on_ferrari_1975.png");
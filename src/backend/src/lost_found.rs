@@ -0,0 +1,494 @@
+use crate::{
+    onnx, preprocess,
+    principal_key::{self, Key},
+    Classification, ClassifyError, ClassifyOptions, InboxPage, Memory, NewReport, Notification,
+    Report, ReportKind, ReportMatch, INBOX_MEMORY_ID, MEMORY_MANAGER, REPORTS_MEMORY_ID,
+    REPORT_CANDIDATES_MEMORY_ID, REPORT_OWNERS_MEMORY_ID,
+};
+use candid::{CandidType, Deserialize, Principal};
+use ic_stable_structures::{storable::Bound, StableBTreeMap, Storable};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+/// The board keeps at most this many open reports, and every principal at
+/// most `MAX_REPORTS_PER_OWNER` of them.
+const MAX_REPORTS: u64 = 2000;
+const MAX_REPORTS_PER_OWNER: usize = 10;
+
+/// Reports are deleted 60 days after they were posted.
+const REPORT_TTL_NANOS: u64 = 60 * 24 * 60 * 60 * 1_000_000_000;
+
+/// A report is only compared with this many of the newest reports of the
+/// other kind, which bounds the work of posting a report.
+const MAX_CANDIDATES: usize = 500;
+
+const MAX_PHOTOS: usize = 3;
+const MAX_TEXT_CHARS: usize = 200;
+
+/// The number of breeds kept per report.
+const BREEDS: u32 = 5;
+
+/// A match scores the similarity of the photos and the agreement of the
+/// breeds, weighted like this.
+const SIMILARITY_WEIGHT: f32 = 0.7;
+const BREED_WEIGHT: f32 = 0.3;
+
+/// Matches that score at least this much are sent to the inboxes of both
+/// reporters, at most `MAX_NOTIFIED` for every new report.
+const MIN_NOTIFIED_SCORE: f32 = 0.65;
+const MAX_NOTIFIED: usize = 5;
+
+const DEFAULT_MATCHES: u32 = 10;
+const MAX_MATCHES: u32 = 50;
+
+/// The oldest notifications of a principal are deleted beyond this.
+const MAX_NOTIFICATIONS: usize = 200;
+const DEFAULT_PAGE_SIZE: u32 = 20;
+const MAX_PAGE_SIZE: u32 = 100;
+
+#[derive(CandidType, Deserialize)]
+struct StoredReport {
+    owner: Principal,
+    kind: ReportKind,
+    created_at: u64,
+    area: String,
+    contact: String,
+    /// The most probable breeds, averaged over the photos.
+    breeds: Vec<Classification>,
+    /// The unit length embedding of every photo.
+    embeddings: Vec<Vec<f32>>,
+    thumbnails: Vec<Vec<u8>>,
+}
+
+impl Storable for StoredReport {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(candid::encode_one(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        candid::decode_one(&bytes).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+impl Storable for Notification {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(candid::encode_one(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        candid::decode_one(&bytes).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+thread_local! {
+    static REPORTS: RefCell<StableBTreeMap<u64, StoredReport, Memory>> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(REPORTS_MEMORY_ID))),
+    );
+
+    // The ids of the reports of every kind, keyed by the kind and
+    // `u64::MAX - id` so that the newest report of a kind comes first.
+    static CANDIDATES: RefCell<StableBTreeMap<(u32, u64), (), Memory>> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(REPORT_CANDIDATES_MEMORY_ID))),
+    );
+
+    // The ids of the reports of every owner.
+    static OWNERS: RefCell<StableBTreeMap<Key, (), Memory>> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(REPORT_OWNERS_MEMORY_ID))),
+    );
+
+    // The notifications of every principal. Ids grow with time, so the
+    // oldest notification of a principal comes first.
+    static INBOX: RefCell<StableBTreeMap<Key, Notification, Memory>> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(INBOX_MEMORY_ID))),
+    );
+}
+
+fn candidate_key(kind: ReportKind, id: u64) -> (u32, u64) {
+    let kind = match kind {
+        ReportKind::Lost => 0,
+        ReportKind::Found => 1,
+    };
+    (kind, u64::MAX - id)
+}
+
+fn insert(id: u64, report: StoredReport) {
+    CANDIDATES.with_borrow_mut(|candidates| candidates.insert(candidate_key(report.kind, id), ()));
+    OWNERS.with_borrow_mut(|owners| owners.insert(Key::new(report.owner, id), ()));
+    REPORTS.with_borrow_mut(|reports| reports.insert(id, report));
+}
+
+fn remove(id: u64) -> Option<StoredReport> {
+    let report = REPORTS.with_borrow_mut(|reports| reports.remove(&id))?;
+    CANDIDATES.with_borrow_mut(|candidates| candidates.remove(&candidate_key(report.kind, id)));
+    OWNERS.with_borrow_mut(|owners| owners.remove(&Key::new(report.owner, id)));
+    Some(report)
+}
+
+/// Indexes the reports posted before their kinds and owners were kept.
+pub fn setup() {
+    if OWNERS.with_borrow(|owners| !owners.is_empty()) {
+        return;
+    }
+    REPORTS.with_borrow(|reports| {
+        for (id, report) in reports.iter() {
+            CANDIDATES.with_borrow_mut(|candidates| {
+                candidates.insert(candidate_key(report.kind, id), ())
+            });
+            OWNERS.with_borrow_mut(|owners| owners.insert(Key::new(report.owner, id), ()));
+        }
+    });
+}
+
+/// Reports with a lower id were posted more than `REPORT_TTL_NANOS` ago.
+/// Ids are the time a report was posted, or slightly later.
+fn oldest_open_id(now: u64) -> u64 {
+    now.saturating_sub(REPORT_TTL_NANOS)
+}
+
+/// Deletes the reports that were posted more than `REPORT_TTL_NANOS` ago.
+fn expire(now: u64) {
+    let cutoff = oldest_open_id(now);
+    let expired: Vec<u64> =
+        REPORTS.with_borrow(|reports| reports.range(..cutoff).map(|(id, _)| id).collect());
+    for id in expired {
+        remove(id);
+    }
+}
+
+fn invalid_request(message: String) -> ClassifyError {
+    ClassifyError::InvalidRequest { message }
+}
+
+/// Trims the text and checks that it is neither empty nor too long.
+fn text(name: &str, value: String) -> Result<String, ClassifyError> {
+    let value = value.trim();
+    if value.is_empty() || value.chars().count() > MAX_TEXT_CHARS {
+        return Err(invalid_request(format!(
+            "{} must have between 1 and {} characters",
+            name, MAX_TEXT_CHARS
+        )));
+    }
+    Ok(value.to_string())
+}
+
+/// Returns the most probable breeds of the animal in the photo and the
+/// embedding of the photo.
+fn analyse(photo: &[u8]) -> Result<(Vec<Classification>, Vec<f32>), ClassifyError> {
+    let options = ClassifyOptions {
+        top_k: Some(BREEDS),
+        animals_only: Some(true),
+        ..Default::default()
+    };
+    match onnx::classify_and_embed(photo, &options)? {
+        (onnx::Verdict::Labels(labels), embedding) => Ok((labels, embedding)),
+        (onnx::Verdict::NotAnimal(not_animal), _) => Err(invalid_request(format!(
+            "the photo does not show an animal but a {}",
+            not_animal.top_label.label
+        ))),
+    }
+}
+
+/// Averages the breeds of several photos of the same animal.
+fn average(photos: Vec<Vec<Classification>>) -> Vec<Classification> {
+    let count = photos.len() as f32;
+    let mut breeds: BTreeMap<String, Classification> = BTreeMap::new();
+    for classification in photos.into_iter().flatten() {
+        let breed = breeds
+            .entry(classification.label.clone())
+            .or_insert(Classification {
+                score: 0.0,
+                raw_logit: 0.0,
+                ..classification.clone()
+            });
+        breed.score += classification.score / count;
+        breed.raw_logit += classification.raw_logit / count;
+    }
+    let mut breeds: Vec<Classification> = breeds.into_values().collect();
+    breeds.sort_by(|a, b| b.score.total_cmp(&a.score));
+    breeds.truncate(BREEDS as usize);
+    breeds
+}
+
+/// The probability mass that two breed distributions share, in [0, 1].
+fn breed_agreement(a: &[Classification], b: &[Classification]) -> f32 {
+    a.iter()
+        .filter_map(|breed| {
+            let other = b.iter().find(|other| other.label == breed.label)?;
+            Some(breed.score.min(other.score))
+        })
+        .sum()
+}
+
+/// The highest cosine similarity of a photo of one report to a photo of the
+/// other. Embeddings of a different size come from another model and are
+/// skipped.
+fn similarity(a: &StoredReport, b: &StoredReport) -> f32 {
+    a.embeddings
+        .iter()
+        .flat_map(|x| b.embeddings.iter().map(move |y| (x, y)))
+        .filter(|(x, y)| x.len() == y.len())
+        .map(|(x, y)| x.iter().zip(y.iter()).map(|(x, y)| x * y).sum::<f32>())
+        .fold(-1.0, f32::max)
+}
+
+fn score(report: &StoredReport, candidate_id: u64, candidate: &StoredReport) -> ReportMatch {
+    let similarity = similarity(report, candidate);
+    let breed_agreement = breed_agreement(&report.breeds, &candidate.breeds);
+    ReportMatch {
+        report_id: candidate_id,
+        score: SIMILARITY_WEIGHT * similarity + BREED_WEIGHT * breed_agreement,
+        similarity,
+        breed_agreement,
+    }
+}
+
+/// Ranks the newest `MAX_CANDIDATES` open reports of the other kind by how
+/// well they match the report, best first.
+fn rank(report: &StoredReport) -> Vec<ReportMatch> {
+    let other_kind = match report.kind {
+        ReportKind::Lost => ReportKind::Found,
+        ReportKind::Found => ReportKind::Lost,
+    };
+    let newest = candidate_key(other_kind, u64::MAX);
+    let oldest = candidate_key(other_kind, oldest_open_id(ic_cdk::api::time()));
+    let ids: Vec<u64> = CANDIDATES.with_borrow(|candidates| {
+        candidates
+            .range(newest..=oldest)
+            .take(MAX_CANDIDATES)
+            .map(|((_, key), _)| u64::MAX - key)
+            .collect()
+    });
+    let mut matches: Vec<ReportMatch> = REPORTS.with_borrow(|reports| {
+        ids.into_iter()
+            .filter_map(|id| Some(score(report, id, &reports.get(&id)?)))
+            .collect()
+    });
+    matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+    matches
+}
+
+/// Posts a report of the owner and notifies the reporters of the best
+/// matching reports of the other kind, and the owner, about the matches.
+pub fn post(owner: Principal, report: NewReport) -> Result<u64, ClassifyError> {
+    if owner == Principal::anonymous() {
+        return Err(invalid_request(
+            "anonymous callers cannot post reports".to_string(),
+        ));
+    }
+    let area = text("area", report.area)?;
+    let contact = text("contact", report.contact)?;
+    if report.photos.is_empty() || report.photos.len() > MAX_PHOTOS {
+        return Err(invalid_request(format!(
+            "a report needs between 1 and {} photos",
+            MAX_PHOTOS
+        )));
+    }
+    expire(ic_cdk::api::time());
+    if REPORTS.with_borrow(|reports| reports.len()) >= MAX_REPORTS {
+        return Err(ClassifyError::QuotaExceeded {
+            quota: "open reports".to_string(),
+            limit: MAX_REPORTS,
+        });
+    }
+    let owned = OWNERS.with_borrow(|owners| {
+        owners
+            .range(principal_key::range(owner, None))
+            .take(MAX_REPORTS_PER_OWNER)
+            .count()
+    });
+    if owned >= MAX_REPORTS_PER_OWNER {
+        return Err(ClassifyError::QuotaExceeded {
+            quota: "reports per owner".to_string(),
+            limit: MAX_REPORTS_PER_OWNER as u64,
+        });
+    }
+
+    let (breeds, embeddings): (Vec<_>, Vec<_>) = report
+        .photos
+        .iter()
+        .map(|photo| analyse(photo))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .unzip();
+    let breeds = average(breeds);
+    let thumbnails = report
+        .photos
+        .iter()
+        .filter_map(|photo| preprocess::thumbnail(photo))
+        .collect();
+    let created_at = ic_cdk::api::time();
+    let stored = StoredReport {
+        owner,
+        kind: report.kind,
+        created_at,
+        area,
+        contact,
+        breeds,
+        embeddings,
+        thumbnails,
+    };
+
+    let id = REPORTS.with_borrow(|reports| {
+        let mut id = created_at;
+        while reports.contains_key(&id) {
+            id += 1;
+        }
+        id
+    });
+    let matches = rank(&stored);
+    insert(id, stored);
+
+    for candidate in matches
+        .into_iter()
+        .take_while(|candidate| candidate.score >= MIN_NOTIFIED_SCORE)
+        .take(MAX_NOTIFIED)
+    {
+        let Some(other) = REPORTS.with_borrow(|reports| reports.get(&candidate.report_id)) else {
+            continue;
+        };
+        notify(
+            other.owner,
+            candidate.report_id,
+            ReportMatch {
+                report_id: id,
+                ..candidate.clone()
+            },
+        );
+        notify(owner, id, candidate);
+    }
+    Ok(id)
+}
+
+/// Adds a notification about a match of one of the reports of the recipient
+/// to their inbox and deletes their oldest notifications beyond
+/// `MAX_NOTIFICATIONS`.
+fn notify(recipient: Principal, report_id: u64, candidate: ReportMatch) {
+    let created_at = ic_cdk::api::time();
+    INBOX.with_borrow_mut(|inbox| {
+        let mut id = created_at;
+        while inbox.contains_key(&Key::new(recipient, id)) {
+            id += 1;
+        }
+        inbox.insert(
+            Key::new(recipient, id),
+            Notification {
+                id,
+                created_at,
+                report_id,
+                candidate,
+            },
+        );
+        let count = inbox.range(principal_key::range(recipient, None)).count();
+        let expired: Vec<Key> = inbox
+            .range(principal_key::range(recipient, None))
+            .take(count.saturating_sub(MAX_NOTIFICATIONS))
+            .map(|(key, _)| key)
+            .collect();
+        for key in expired {
+            inbox.remove(&key);
+        }
+    });
+}
+
+fn public(id: u64, report: StoredReport) -> Report {
+    Report {
+        id,
+        kind: report.kind,
+        owner: report.owner,
+        created_at: report.created_at,
+        area: report.area,
+        contact: report.contact,
+        breeds: report.breeds,
+        thumbnails: report.thumbnails,
+    }
+}
+
+pub fn get(id: u64) -> Option<Report> {
+    REPORTS.with_borrow(|reports| reports.get(&id).map(|report| public(id, report)))
+}
+
+/// The reports of the owner, oldest first.
+pub fn list(owner: Principal) -> Vec<Report> {
+    let ids: Vec<u64> = OWNERS.with_borrow(|owners| {
+        owners
+            .range(principal_key::range(owner, None))
+            .map(|(key, _)| key.id())
+            .collect()
+    });
+    REPORTS.with_borrow(|reports| {
+        ids.into_iter()
+            .filter_map(|id| reports.get(&id).map(|report| public(id, report)))
+            .collect()
+    })
+}
+
+/// Returns the `k` reports of the other kind that best match the report.
+pub fn matches(id: u64, k: Option<u32>) -> Result<Vec<ReportMatch>, String> {
+    let report = REPORTS
+        .with_borrow(|reports| reports.get(&id))
+        .ok_or_else(|| format!("report {} does not exist", id))?;
+    let k = k.unwrap_or(DEFAULT_MATCHES).clamp(1, MAX_MATCHES) as usize;
+    let mut matches = rank(&report);
+    matches.truncate(k);
+    Ok(matches)
+}
+
+/// Deletes a report of the owner, once the pet is back home. Controllers can
+/// delete any report.
+pub fn close(caller: Principal, id: u64) -> Result<(), String> {
+    let report = REPORTS
+        .with_borrow(|reports| reports.get(&id))
+        .ok_or_else(|| format!("report {} does not exist", id))?;
+    if report.owner != caller && !ic_cdk::api::is_controller(&caller) {
+        return Err(format!("report {} was posted by another principal", id));
+    }
+    remove(id);
+    Ok(())
+}
+
+/// Returns up to `limit` notifications of the recipient that arrived after the
+/// notification with id `cursor`, oldest first.
+pub fn inbox(recipient: Principal, cursor: Option<u64>, limit: Option<u32>) -> InboxPage {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE) as usize;
+    INBOX.with_borrow(|inbox| {
+        let mut notifications: Vec<Notification> = inbox
+            .range(principal_key::range(recipient, cursor))
+            .take(limit + 1)
+            .map(|(_, notification)| notification)
+            .collect();
+        let more = notifications.len() > limit;
+        notifications.truncate(limit);
+        // Without more notifications, the cursor still moves past the last
+        // one, so that polling with it only returns new notifications.
+        let next = notifications
+            .last()
+            .map(|notification| notification.id)
+            .or(cursor);
+        InboxPage {
+            notifications,
+            next,
+            more,
+        }
+    })
+}
+
+/// Deletes the notifications of the recipient up to and including the one
+/// with id `up_to`. Returns the number deleted.
+pub fn acknowledge(recipient: Principal, up_to: u64) -> u64 {
+    INBOX.with_borrow_mut(|inbox| {
+        let keys: Vec<Key> = inbox
+            .range(principal_key::range(recipient, None))
+            .take_while(|(key, _)| key.id() <= up_to)
+            .map(|(key, _)| key)
+            .collect();
+        for key in &keys {
+            inbox.remove(key);
+        }
+        keys.len() as u64
+    })
+}
@@ -0,0 +1,2458 @@
+use crate::{onnx::LABELS, taxonomy::SYNSETS, Classification, CommonName};
+
+/// The locales that common names are available in. English names are the
+/// ImageNet labels themselves, the others come from `NAMES` for the animal
+/// classes and from `OBJECT_NAMES` for the other classes.
+const LOCALES: [&str; 4] = ["en", "id", "es", "fr"];
+
+/// The two-letter ISO 639-1 language codes, sorted.
+const ISO_639_1: [&str; 183] = [
+    "aa", "ab", "ae", "af", "ak", "am", "an", "ar", "as", "av", "ay", "az", "ba", "be", "bg", "bi",
+    "bm", "bn", "bo", "br", "bs", "ca", "ce", "ch", "co", "cr", "cs", "cu", "cv", "cy", "da", "de",
+    "dv", "dz", "ee", "el", "en", "eo", "es", "et", "eu", "fa", "ff", "fi", "fj", "fo", "fr", "fy",
+    "ga", "gd", "gl", "gn", "gu", "gv", "ha", "he", "hi", "ho", "hr", "ht", "hu", "hy", "hz", "ia",
+    "id", "ie", "ig", "ii", "ik", "io", "is", "it", "iu", "ja", "jv", "ka", "kg", "ki", "kj", "kk",
+    "kl", "km", "kn", "ko", "kr", "ks", "ku", "kv", "kw", "ky", "la", "lb", "lg", "li", "ln", "lo",
+    "lt", "lu", "lv", "mg", "mh", "mi", "mk", "ml", "mn", "mr", "ms", "mt", "my", "na", "nb", "nd",
+    "ne", "ng", "nl", "nn", "no", "nr", "nv", "ny", "oc", "oj", "om", "or", "os", "pa", "pi", "pl",
+    "ps", "pt", "qu", "rm", "rn", "ro", "ru", "rw", "sa", "sc", "sd", "se", "sg", "si", "sk", "sl",
+    "sm", "sn", "so", "sq", "sr", "ss", "st", "su", "sv", "sw", "ta", "te", "tg", "th", "ti", "tk",
+    "tl", "tn", "to", "tr", "ts", "tt", "tw", "ty", "ug", "uk", "ur", "uz", "ve", "vi", "vo", "wa",
+    "wo", "xh", "yi", "yo", "za", "zh", "zu",
+];
+
+/// Returns the index of the ImageNet class with the given synset.
+fn index(synset: &str) -> Option<usize> {
+    SYNSETS.binary_search(&synset).ok()
+}
+
+/// Returns the Indonesian, Spanish and French common names of the ImageNet
+/// class with the given index.
+fn translations(index: usize) -> (&'static str, &'static str, &'static str) {
+    match NAMES.get(index) {
+        Some(&(_, id, es, fr)) => (id, es, fr),
+        None => OBJECT_NAMES[index - NAMES.len()],
+    }
+}
+
+/// Returns the language of a locale such as `id` or `es-MX`.
+pub fn language(locale: &str) -> String {
+    locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+}
+
+/// Whether the language is an ISO 639-1 code, as every supported locale is.
+pub fn is_language(language: &str) -> bool {
+    ISO_639_1.binary_search(&language).is_ok()
+}
+
+/// Returns the scientific name of the ImageNet animal class with the given
+/// synset. Breeds share the name of their species and classes that cover
+/// several species are named after the genus or family.
+pub fn scientific_name(synset: &str) -> Option<&'static str> {
+    index(synset)
+        .and_then(|index| NAMES.get(index))
+        .map(|names| names.0)
+}
+
+/// Returns the common name of the ImageNet class with the given synset in the
+/// given locale, or `None` if there is no translation to that locale.
+pub fn common_name(synset: &str, locale: &str) -> Option<&'static str> {
+    let index = index(synset)?;
+    let (id, es, fr) = translations(index);
+    match language(locale).as_str() {
+        "en" => Some(LABELS[index]),
+        "id" => Some(id),
+        "es" => Some(es),
+        "fr" => Some(fr),
+        _ => None,
+    }
+}
+
+/// Returns the common names of the ImageNet class with the given synset in
+/// every supported locale.
+pub fn common_names(synset: &str) -> Vec<CommonName> {
+    LOCALES
+        .iter()
+        .filter_map(|&locale| {
+            common_name(synset, locale).map(|name| CommonName {
+                locale: locale.to_string(),
+                name: name.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the synset of the ImageNet class with the given common name in any
+/// supported locale, ignoring case. Animals come first when a name is shared.
+pub fn synset(name: &str) -> Option<&'static str> {
+    let name = name.trim().to_lowercase();
+    let index = (0..SYNSETS.len()).find(|&index| {
+        let (id, es, fr) = translations(index);
+        [LABELS[index], id, es, fr]
+            .iter()
+            .any(|common_name| common_name.to_lowercase() == name)
+    })?;
+    Some(SYNSETS[index])
+}
+
+/// Returns the ImageNet label with the given name, ignoring case, or the
+/// label of the ImageNet class with the given common name in any supported
+/// locale.
+pub fn canonical_label(name: &str) -> Option<&'static str> {
+    let name = name.trim();
+    LABELS
+        .iter()
+        .find(|label| label.eq_ignore_ascii_case(name))
+        .copied()
+        .or_else(|| Some(LABELS[index(synset(name)?)?]))
+}
+
+/// Replaces the label with its common name in the given locale. Labels that
+/// have no translation, such as the ones that were rolled up to a taxon, keep
+/// their English name.
+pub fn localise(classification: Classification, locale: &str) -> Classification {
+    match classification
+        .synset
+        .as_deref()
+        .and_then(|synset| common_name(synset, locale))
+    {
+        Some(name) => Classification {
+            label: name.to_string(),
+            ..classification
+        },
+        None => classification,
+    }
+}
+
+/// The scientific name and the Indonesian, Spanish and French common names of
+/// the ImageNet animal classes, indexed like `SYNSETS`.
+static NAMES: [(&str, &str, &str, &str); 398] = [
+    ("Tinca tinca", "ikan tench", "tenca", "tanche"),
+    (
+        "Carassius auratus",
+        "ikan mas koki",
+        "carpín dorado",
+        "poisson rouge",
+    ),
+    (
+        "Carcharodon carcharias",
+        "hiu putih besar",
+        "tiburón blanco",
+        "grand requin blanc",
+    ),
+    (
+        "Galeocerdo cuvier",
+        "hiu macan",
+        "tiburón tigre",
+        "requin tigre",
+    ),
+    ("Sphyrna", "hiu martil", "pez martillo", "requin-marteau"),
+    ("Torpedo", "pari listrik", "raya eléctrica", "torpille"),
+    ("Dasyatis", "ikan pari", "pastinaca", "pastenague"),
+    ("Gallus gallus domesticus", "ayam jantan", "gallo", "coq"),
+    (
+        "Gallus gallus domesticus",
+        "ayam betina",
+        "gallina",
+        "poule",
+    ),
+    ("Struthio camelus", "burung unta", "avestruz", "autruche"),
+    (
+        "Fringilla montifringilla",
+        "burung brambling",
+        "pinzón real",
+        "pinson du Nord",
+    ),
+    (
+        "Carduelis carduelis",
+        "burung goldfinch",
+        "jilguero",
+        "chardonneret élégant",
+    ),
+    (
+        "Haemorhous mexicanus",
+        "burung finch rumah",
+        "pinzón mexicano",
+        "roselin familier",
+    ),
+    (
+        "Junco hyemalis",
+        "burung junco",
+        "junco pizarroso",
+        "junco ardoisé",
+    ),
+    (
+        "Passerina cyanea",
+        "burung bunting nila",
+        "azulillo índigo",
+        "passerin indigo",
+    ),
+    (
+        "Turdus migratorius",
+        "burung robin",
+        "petirrojo americano",
+        "merle d'Amérique",
+    ),
+    ("Pycnonotus", "burung cucak", "bulbul", "bulbul"),
+    (
+        "Garrulus glandarius",
+        "burung jay",
+        "arrendajo",
+        "geai des chênes",
+    ),
+    ("Pica pica", "burung magpie", "urraca", "pie bavarde"),
+    ("Poecile", "burung chickadee", "carbonero", "mésange"),
+    (
+        "Cinclus cinclus",
+        "burung dipper",
+        "mirlo acuático",
+        "cincle plongeur",
+    ),
+    ("Milvus", "burung elang paria", "milano", "milan"),
+    (
+        "Haliaeetus leucocephalus",
+        "elang botak",
+        "águila calva",
+        "pygargue à tête blanche",
+    ),
+    ("Aegypiinae", "burung nasar", "buitre", "vautour"),
+    (
+        "Strix nebulosa",
+        "burung hantu abu-abu besar",
+        "cárabo lapón",
+        "chouette lapone",
+    ),
+    (
+        "Salamandra salamandra",
+        "salamander api",
+        "salamandra común",
+        "salamandre tachetée",
+    ),
+    (
+        "Lissotriton vulgaris",
+        "kadal air",
+        "tritón común",
+        "triton ponctué",
+    ),
+    (
+        "Notophthalmus viridescens",
+        "kadal air muda",
+        "tritón juvenil",
+        "triton juvénile",
+    ),
+    (
+        "Ambystoma maculatum",
+        "salamander tutul",
+        "salamandra moteada",
+        "salamandre maculée",
+    ),
+    ("Ambystoma mexicanum", "aksolotl", "ajolote", "axolotl"),
+    (
+        "Lithobates catesbeianus",
+        "katak banteng",
+        "rana toro",
+        "ouaouaron",
+    ),
+    ("Hylidae", "katak pohon", "rana arborícola", "rainette"),
+    (
+        "Ascaphus truei",
+        "katak berekor",
+        "rana con cola",
+        "grenouille à queue",
+    ),
+    (
+        "Caretta caretta",
+        "penyu tempayan",
+        "tortuga boba",
+        "tortue caouanne",
+    ),
+    (
+        "Dermochelys coriacea",
+        "penyu belimbing",
+        "tortuga laúd",
+        "tortue luth",
+    ),
+    (
+        "Kinosternon",
+        "kura-kura lumpur",
+        "tortuga de fango",
+        "tortue de boue",
+    ),
+    (
+        "Malaclemys terrapin",
+        "kura-kura terrapin",
+        "galápago",
+        "tortue terrapène",
+    ),
+    (
+        "Terrapene",
+        "kura-kura kotak",
+        "tortuga de caja",
+        "tortue-boîte",
+    ),
+    (
+        "Coleonyx",
+        "tokek belang",
+        "geco bandeado",
+        "gecko à bandes",
+    ),
+    (
+        "Iguana iguana",
+        "iguana hijau",
+        "iguana verde",
+        "iguane vert",
+    ),
+    (
+        "Anolis carolinensis",
+        "anolis hijau",
+        "anolis verde",
+        "anolis vert",
+    ),
+    (
+        "Aspidoscelis",
+        "kadal whiptail",
+        "lagartija cola de látigo",
+        "cnémidophore",
+    ),
+    ("Agama", "kadal agama", "agama", "agame"),
+    (
+        "Chlamydosaurus kingii",
+        "kadal berjumbai",
+        "clamidosaurio",
+        "lézard à collerette",
+    ),
+    ("Elgaria", "kadal aligator", "lagarto caimán", "gerrhonote"),
+    (
+        "Heloderma suspectum",
+        "monster gila",
+        "monstruo de Gila",
+        "monstre de Gila",
+    ),
+    (
+        "Lacerta viridis",
+        "kadal hijau",
+        "lagarto verde",
+        "lézard vert",
+    ),
+    (
+        "Chamaeleo chamaeleon",
+        "bunglon afrika",
+        "camaleón africano",
+        "caméléon commun",
+    ),
+    (
+        "Varanus komodoensis",
+        "komodo",
+        "dragón de Komodo",
+        "dragon de Komodo",
+    ),
+    (
+        "Crocodylus niloticus",
+        "buaya afrika",
+        "cocodrilo africano",
+        "crocodile du Nil",
+    ),
+    (
+        "Alligator mississippiensis",
+        "aligator amerika",
+        "aligátor americano",
+        "alligator d'Amérique",
+    ),
+    ("Triceratops", "triceratops", "tricerátops", "tricératops"),
+    (
+        "Carphophis amoenus",
+        "ular cacing",
+        "culebra gusano",
+        "serpent-ver",
+    ),
+    (
+        "Diadophis punctatus",
+        "ular cincin",
+        "culebra de collar",
+        "couleuvre à collier",
+    ),
+    (
+        "Heterodon",
+        "ular hidung babi",
+        "culebra nariz de cerdo",
+        "couleuvre à nez retroussé",
+    ),
+    (
+        "Opheodrys",
+        "ular hijau",
+        "culebra verde",
+        "couleuvre verte",
+    ),
+    ("Lampropeltis", "ular raja", "falsa coral", "serpent-roi"),
+    (
+        "Thamnophis",
+        "ular garter",
+        "culebra de jarretera",
+        "couleuvre rayée",
+    ),
+    ("Nerodia", "ular air", "culebra de agua", "couleuvre d'eau"),
+    ("Oxybelis", "ular pucuk", "bejuquilla", "serpent-liane"),
+    (
+        "Hypsiglena",
+        "ular malam",
+        "culebra nocturna",
+        "couleuvre nocturne",
+    ),
+    (
+        "Boa constrictor",
+        "ular boa",
+        "boa constrictor",
+        "boa constricteur",
+    ),
+    (
+        "Python sebae",
+        "piton batu",
+        "pitón de roca",
+        "python de Seba",
+    ),
+    ("Naja naja", "kobra india", "cobra india", "cobra indien"),
+    (
+        "Dendroaspis viridis",
+        "mamba hijau",
+        "mamba verde",
+        "mamba vert",
+    ),
+    (
+        "Hydrophiinae",
+        "ular laut",
+        "serpiente marina",
+        "serpent marin",
+    ),
+    (
+        "Cerastes cerastes",
+        "ular beludak bertanduk",
+        "víbora cornuda",
+        "vipère à cornes",
+    ),
+    (
+        "Crotalus adamanteus",
+        "ular derik punggung berlian",
+        "serpiente de cascabel diamantina",
+        "crotale diamantin",
+    ),
+    (
+        "Crotalus cerastes",
+        "ular derik sidewinder",
+        "crótalo cornudo",
+        "crotale cornu",
+    ),
+    ("Trilobita", "trilobita", "trilobite", "trilobite"),
+    ("Opiliones", "laba-laba harvestman", "opilión", "opilion"),
+    ("Scorpiones", "kalajengking", "escorpión", "scorpion"),
+    (
+        "Argiope aurantia",
+        "laba-laba kebun kuning hitam",
+        "araña de jardín amarilla",
+        "argiope jaune",
+    ),
+    (
+        "Araneus cavaticus",
+        "laba-laba lumbung",
+        "araña de granero",
+        "épeire des granges",
+    ),
+    (
+        "Araneus diadematus",
+        "laba-laba kebun",
+        "araña de jardín",
+        "épeire diadème",
+    ),
+    (
+        "Latrodectus",
+        "laba-laba janda hitam",
+        "viuda negra",
+        "veuve noire",
+    ),
+    ("Theraphosidae", "tarantula", "tarántula", "mygale"),
+    (
+        "Lycosidae",
+        "laba-laba serigala",
+        "araña lobo",
+        "araignée-loup",
+    ),
+    ("Ixodida", "caplak", "garrapata", "tique"),
+    ("Chilopoda", "kelabang", "ciempiés", "scolopendre"),
+    (
+        "Lyrurus tetrix",
+        "ayam hutan hitam",
+        "gallo lira",
+        "tétras lyre",
+    ),
+    ("Lagopus", "ptarmigan", "perdiz nival", "lagopède"),
+    (
+        "Bonasa umbellus",
+        "belibis ruffed",
+        "grévol engolado",
+        "gélinotte huppée",
+    ),
+    (
+        "Tympanuchus",
+        "ayam padang rumput",
+        "gallo de las praderas",
+        "tétras des prairies",
+    ),
+    ("Pavo cristatus", "burung merak", "pavo real", "paon"),
+    ("Coturnix", "burung puyuh", "codorniz", "caille"),
+    ("Perdix perdix", "ayam hutan partridge", "perdiz", "perdrix"),
+    (
+        "Psittacus erithacus",
+        "burung nuri abu-abu afrika",
+        "loro gris africano",
+        "perroquet gris du Gabon",
+    ),
+    ("Ara", "burung makaw", "guacamayo", "ara"),
+    (
+        "Cacatua galerita",
+        "kakatua jambul kuning",
+        "cacatúa galerita",
+        "cacatoès à huppe jaune",
+    ),
+    ("Trichoglossus", "burung perkici", "lori", "loriquet"),
+    ("Centropus", "burung bubut", "cucal", "coucal"),
+    ("Merops", "burung kirik-kirik", "abejaruco", "guêpier"),
+    ("Bucerotidae", "burung rangkong", "cálao", "calao"),
+    ("Trochilidae", "burung kolibri", "colibrí", "colibri"),
+    ("Galbulidae", "burung jakamar", "jacamar", "jacamar"),
+    ("Ramphastos", "burung tukan", "tucán", "toucan"),
+    (
+        "Anas platyrhynchos",
+        "bebek jantan",
+        "pato macho",
+        "canard mâle",
+    ),
+    (
+        "Mergus serrator",
+        "bebek merganser dada merah",
+        "serreta mediana",
+        "harle huppé",
+    ),
+    ("Anser", "angsa", "ganso", "oie"),
+    ("Cygnus atratus", "angsa hitam", "cisne negro", "cygne noir"),
+    (
+        "Elephantidae",
+        "gajah bergading",
+        "elefante con colmillos",
+        "éléphant à défenses",
+    ),
+    (
+        "Tachyglossus aculeatus",
+        "landak semut",
+        "equidna",
+        "échidné",
+    ),
+    (
+        "Ornithorhynchus anatomicus",
+        "platipus",
+        "ornitorrinco",
+        "ornithorynque",
+    ),
+    ("Macropus", "walabi", "ualabí", "wallaby"),
+    ("Phascolarctos cinereus", "koala", "koala", "koala"),
+    ("Vombatus ursinus", "wombat", "wómbat", "wombat"),
+    ("Scyphozoa", "ubur-ubur", "medusa", "méduse"),
+    (
+        "Actiniaria",
+        "anemon laut",
+        "anémona de mar",
+        "anémone de mer",
+    ),
+    ("Diploria", "karang otak", "coral cerebro", "corail cerveau"),
+    ("Turbellaria", "cacing pipih", "gusano plano", "ver plat"),
+    ("Nematoda", "cacing gilig", "nematodo", "nématode"),
+    ("Strombidae", "keong gonggong", "caracola", "strombe"),
+    ("Gastropoda", "siput", "caracol", "escargot"),
+    ("Stylommatophora", "siput telanjang", "babosa", "limace"),
+    ("Nudibranchia", "siput laut", "babosa marina", "nudibranche"),
+    ("Polyplacophora", "kiton", "quitón", "chiton"),
+    ("Nautilus pompilius", "nautilus", "nautilo", "nautile"),
+    (
+        "Metacarcinus magister",
+        "kepiting dungeness",
+        "cangrejo Dungeness",
+        "crabe dormeur du Pacifique",
+    ),
+    (
+        "Cancer irroratus",
+        "kepiting batu",
+        "cangrejo de roca",
+        "crabe de roche",
+    ),
+    (
+        "Uca",
+        "kepiting biola",
+        "cangrejo violinista",
+        "crabe violoniste",
+    ),
+    (
+        "Paralithodes camtschaticus",
+        "kepiting raja",
+        "cangrejo real",
+        "crabe royal",
+    ),
+    (
+        "Homarus americanus",
+        "lobster amerika",
+        "bogavante americano",
+        "homard américain",
+    ),
+    ("Palinuridae", "lobster berduri", "langosta", "langouste"),
+    ("Astacoidea", "udang karang", "cangrejo de río", "écrevisse"),
+    (
+        "Paguroidea",
+        "kelomang",
+        "cangrejo ermitaño",
+        "bernard-l'ermite",
+    ),
+    ("Isopoda", "isopoda", "isópodo", "isopode"),
+    (
+        "Ciconia ciconia",
+        "bangau putih",
+        "cigüeña blanca",
+        "cigogne blanche",
+    ),
+    (
+        "Ciconia nigra",
+        "bangau hitam",
+        "cigüeña negra",
+        "cigogne noire",
+    ),
+    ("Platalea", "burung sendok", "espátula", "spatule"),
+    ("Phoenicopterus", "flamingo", "flamenco", "flamant"),
+    (
+        "Egretta caerulea",
+        "kuntul biru kecil",
+        "garceta azul",
+        "aigrette bleue",
+    ),
+    (
+        "Ardea alba",
+        "kuntul besar",
+        "garceta grande",
+        "grande aigrette",
+    ),
+    ("Botaurus", "burung bittern", "avetoro", "butor"),
+    ("Gruidae", "burung jenjang", "grulla", "grue"),
+    (
+        "Aramus guarauna",
+        "burung limpkin",
+        "carrao",
+        "courlan brun",
+    ),
+    ("Porphyrio porphyrio", "burung mandar", "calamón", "talève"),
+    (
+        "Fulica americana",
+        "burung koot amerika",
+        "focha americana",
+        "foulque d'Amérique",
+    ),
+    ("Otididae", "burung kuau", "avutarda", "outarde"),
+    (
+        "Arenaria interpres",
+        "burung trinil pembalik batu",
+        "vuelvepiedras",
+        "tournepierre à collier",
+    ),
+    (
+        "Calidris alpina",
+        "burung kedidi",
+        "correlimos común",
+        "bécasseau variable",
+    ),
+    (
+        "Tringa totanus",
+        "burung trinil kaki merah",
+        "archibebe común",
+        "chevalier gambette",
+    ),
+    ("Limnodromus", "burung trinil lumpur", "agujeta", "bécassin"),
+    ("Haematopus", "burung kedidir", "ostrero", "huîtrier"),
+    ("Pelecanus", "burung pelikan", "pelícano", "pélican"),
+    (
+        "Aptenodytes patagonicus",
+        "penguin raja",
+        "pingüino rey",
+        "manchot royal",
+    ),
+    ("Diomedeidae", "burung albatros", "albatros", "albatros"),
+    (
+        "Eschrichtius robustus",
+        "paus abu-abu",
+        "ballena gris",
+        "baleine grise",
+    ),
+    ("Orcinus orca", "paus pembunuh", "orca", "orque"),
+    ("Dugong dugon", "duyung", "dugongo", "dugong"),
+    ("Otariinae", "singa laut", "león marino", "otarie"),
+    (
+        "Canis lupus familiaris",
+        "chihuahua",
+        "chihuahua",
+        "chihuahua",
+    ),
+    (
+        "Canis lupus familiaris",
+        "japanese chin",
+        "chin japonés",
+        "épagneul japonais",
+    ),
+    (
+        "Canis lupus familiaris",
+        "maltese",
+        "bichón maltés",
+        "bichon maltais",
+    ),
+    (
+        "Canis lupus familiaris",
+        "pekingese",
+        "pequinés",
+        "pékinois",
+    ),
+    ("Canis lupus familiaris", "shih tzu", "shih tzu", "shih tzu"),
+    (
+        "Canis lupus familiaris",
+        "king charles spaniel blenheim",
+        "cavalier king charles blenheim",
+        "cavalier king charles blenheim",
+    ),
+    ("Canis lupus familiaris", "papillon", "papillón", "papillon"),
+    (
+        "Canis lupus familiaris",
+        "toy terrier",
+        "toy terrier",
+        "toy terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "rhodesian ridgeback",
+        "crestado rodesiano",
+        "chien de Rhodésie à crête dorsale",
+    ),
+    (
+        "Canis lupus familiaris",
+        "afghan hound",
+        "galgo afgano",
+        "lévrier afghan",
+    ),
+    (
+        "Canis lupus familiaris",
+        "basset hound",
+        "basset hound",
+        "basset hound",
+    ),
+    ("Canis lupus familiaris", "beagle", "beagle", "beagle"),
+    (
+        "Canis lupus familiaris",
+        "bloodhound",
+        "sabueso de San Huberto",
+        "chien de Saint-Hubert",
+    ),
+    (
+        "Canis lupus familiaris",
+        "bluetick coonhound",
+        "coonhound bluetick",
+        "coonhound bluetick",
+    ),
+    (
+        "Canis lupus familiaris",
+        "black and tan coonhound",
+        "coonhound negro y fuego",
+        "coonhound noir et feu",
+    ),
+    (
+        "Canis lupus familiaris",
+        "treeing walker coonhound",
+        "coonhound walker",
+        "coonhound walker",
+    ),
+    (
+        "Canis lupus familiaris",
+        "english foxhound",
+        "foxhound inglés",
+        "foxhound anglais",
+    ),
+    (
+        "Canis lupus familiaris",
+        "redbone coonhound",
+        "coonhound redbone",
+        "coonhound redbone",
+    ),
+    ("Canis lupus familiaris", "borzoi", "borzoi", "barzoï"),
+    (
+        "Canis lupus familiaris",
+        "irish wolfhound",
+        "lobero irlandés",
+        "lévrier irlandais",
+    ),
+    (
+        "Canis lupus familiaris",
+        "italian greyhound",
+        "galgo italiano",
+        "petit lévrier italien",
+    ),
+    ("Canis lupus familiaris", "whippet", "whippet", "whippet"),
+    (
+        "Canis lupus familiaris",
+        "ibizan hound",
+        "podenco ibicenco",
+        "podenco d'Ibiza",
+    ),
+    (
+        "Canis lupus familiaris",
+        "norwegian elkhound",
+        "cazador de alces noruego",
+        "chien d'élan norvégien",
+    ),
+    (
+        "Canis lupus familiaris",
+        "otterhound",
+        "perro de nutrias",
+        "chien à loutre",
+    ),
+    ("Canis lupus familiaris", "saluki", "saluki", "saluki"),
+    (
+        "Canis lupus familiaris",
+        "scottish deerhound",
+        "lebrel escocés",
+        "lévrier écossais",
+    ),
+    (
+        "Canis lupus familiaris",
+        "weimaraner",
+        "braco de Weimar",
+        "braque de Weimar",
+    ),
+    (
+        "Canis lupus familiaris",
+        "staffordshire bull terrier",
+        "staffordshire bull terrier",
+        "staffordshire bull terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "american staffordshire terrier",
+        "american staffordshire terrier",
+        "american staffordshire terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "bedlington terrier",
+        "bedlington terrier",
+        "bedlington terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "border terrier",
+        "border terrier",
+        "border terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "kerry blue terrier",
+        "kerry blue terrier",
+        "kerry blue terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "irish terrier",
+        "terrier irlandés",
+        "terrier irlandais",
+    ),
+    (
+        "Canis lupus familiaris",
+        "norfolk terrier",
+        "norfolk terrier",
+        "norfolk terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "norwich terrier",
+        "norwich terrier",
+        "norwich terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "yorkshire terrier",
+        "yorkshire terrier",
+        "yorkshire terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "wire fox terrier",
+        "fox terrier de pelo duro",
+        "fox-terrier à poil dur",
+    ),
+    (
+        "Canis lupus familiaris",
+        "lakeland terrier",
+        "lakeland terrier",
+        "lakeland terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "sealyham terrier",
+        "sealyham terrier",
+        "sealyham terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "airedale terrier",
+        "airedale terrier",
+        "airedale terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "cairn terrier",
+        "cairn terrier",
+        "cairn terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "australian terrier",
+        "terrier australiano",
+        "terrier australien",
+    ),
+    (
+        "Canis lupus familiaris",
+        "dandie dinmont terrier",
+        "dandie dinmont terrier",
+        "dandie dinmont terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "boston terrier",
+        "boston terrier",
+        "terrier de Boston",
+    ),
+    (
+        "Canis lupus familiaris",
+        "schnauzer mini",
+        "schnauzer miniatura",
+        "schnauzer nain",
+    ),
+    (
+        "Canis lupus familiaris",
+        "schnauzer raksasa",
+        "schnauzer gigante",
+        "schnauzer géant",
+    ),
+    (
+        "Canis lupus familiaris",
+        "schnauzer standar",
+        "schnauzer mediano",
+        "schnauzer moyen",
+    ),
+    (
+        "Canis lupus familiaris",
+        "scottish terrier",
+        "terrier escocés",
+        "scottish terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "tibetan terrier",
+        "terrier tibetano",
+        "terrier tibétain",
+    ),
+    (
+        "Canis lupus familiaris",
+        "silky terrier",
+        "silky terrier",
+        "silky terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "soft-coated wheaten terrier",
+        "terrier irlandés de pelo suave",
+        "terrier irlandais à poil doux",
+    ),
+    (
+        "Canis lupus familiaris",
+        "west highland white terrier",
+        "west highland white terrier",
+        "west highland white terrier",
+    ),
+    (
+        "Canis lupus familiaris",
+        "lhasa apso",
+        "lhasa apso",
+        "lhassa apso",
+    ),
+    (
+        "Canis lupus familiaris",
+        "flat-coated retriever",
+        "retriever de pelo liso",
+        "retriever à poil plat",
+    ),
+    (
+        "Canis lupus familiaris",
+        "curly-coated retriever",
+        "retriever de pelo rizado",
+        "retriever à poil bouclé",
+    ),
+    (
+        "Canis lupus familiaris",
+        "golden retriever",
+        "golden retriever",
+        "golden retriever",
+    ),
+    (
+        "Canis lupus familiaris",
+        "labrador retriever",
+        "labrador retriever",
+        "labrador retriever",
+    ),
+    (
+        "Canis lupus familiaris",
+        "chesapeake bay retriever",
+        "retriever de la bahía de Chesapeake",
+        "retriever de la baie de Chesapeake",
+    ),
+    (
+        "Canis lupus familiaris",
+        "german shorthaired pointer",
+        "braco alemán de pelo corto",
+        "braque allemand à poil court",
+    ),
+    (
+        "Canis lupus familiaris",
+        "vizsla",
+        "braco húngaro",
+        "braque hongrois",
+    ),
+    (
+        "Canis lupus familiaris",
+        "english setter",
+        "setter inglés",
+        "setter anglais",
+    ),
+    (
+        "Canis lupus familiaris",
+        "irish setter",
+        "setter irlandés",
+        "setter irlandais",
+    ),
+    (
+        "Canis lupus familiaris",
+        "gordon setter",
+        "setter Gordon",
+        "setter Gordon",
+    ),
+    (
+        "Canis lupus familiaris",
+        "brittany",
+        "spaniel bretón",
+        "épagneul breton",
+    ),
+    (
+        "Canis lupus familiaris",
+        "clumber spaniel",
+        "clumber spaniel",
+        "clumber spaniel",
+    ),
+    (
+        "Canis lupus familiaris",
+        "english springer spaniel",
+        "springer spaniel inglés",
+        "springer anglais",
+    ),
+    (
+        "Canis lupus familiaris",
+        "welsh springer spaniel",
+        "springer spaniel galés",
+        "springer gallois",
+    ),
+    (
+        "Canis lupus familiaris",
+        "cocker spaniel",
+        "cocker spaniel",
+        "cocker spaniel",
+    ),
+    (
+        "Canis lupus familiaris",
+        "sussex spaniel",
+        "sussex spaniel",
+        "sussex spaniel",
+    ),
+    (
+        "Canis lupus familiaris",
+        "irish water spaniel",
+        "perro de aguas irlandés",
+        "épagneul d'eau irlandais",
+    ),
+    ("Canis lupus familiaris", "kuvasz", "kuvasz", "kuvasz"),
+    (
+        "Canis lupus familiaris",
+        "schipperke",
+        "schipperke",
+        "schipperke",
+    ),
+    (
+        "Canis lupus familiaris",
+        "groenendael",
+        "pastor belga groenendael",
+        "berger belge groenendael",
+    ),
+    (
+        "Canis lupus familiaris",
+        "malinois",
+        "pastor belga malinois",
+        "berger belge malinois",
+    ),
+    (
+        "Canis lupus familiaris",
+        "briard",
+        "pastor de Brie",
+        "berger de Brie",
+    ),
+    (
+        "Canis lupus familiaris",
+        "kelpie",
+        "kelpie australiano",
+        "kelpie australien",
+    ),
+    ("Canis lupus familiaris", "komondor", "komondor", "komondor"),
+    (
+        "Canis lupus familiaris",
+        "old english sheepdog",
+        "antiguo perro pastor inglés",
+        "bobtail",
+    ),
+    (
+        "Canis lupus familiaris",
+        "shetland sheepdog",
+        "pastor de las Shetland",
+        "berger des Shetland",
+    ),
+    ("Canis lupus familiaris", "collie", "collie", "colley"),
+    (
+        "Canis lupus familiaris",
+        "border collie",
+        "border collie",
+        "border collie",
+    ),
+    (
+        "Canis lupus familiaris",
+        "bouvier des flandres",
+        "boyero de Flandes",
+        "bouvier des Flandres",
+    ),
+    (
+        "Canis lupus familiaris",
+        "rottweiler",
+        "rottweiler",
+        "rottweiler",
+    ),
+    (
+        "Canis lupus familiaris",
+        "anjing gembala jerman",
+        "pastor alemán",
+        "berger allemand",
+    ),
+    (
+        "Canis lupus familiaris",
+        "doberman",
+        "dóberman",
+        "dobermann",
+    ),
+    (
+        "Canis lupus familiaris",
+        "pinscher mini",
+        "pinscher miniatura",
+        "pinscher nain",
+    ),
+    (
+        "Canis lupus familiaris",
+        "greater swiss mountain dog",
+        "gran boyero suizo",
+        "grand bouvier suisse",
+    ),
+    (
+        "Canis lupus familiaris",
+        "bernese mountain dog",
+        "boyero de Berna",
+        "bouvier bernois",
+    ),
+    (
+        "Canis lupus familiaris",
+        "appenzeller",
+        "boyero de Appenzell",
+        "bouvier de l'Appenzell",
+    ),
+    (
+        "Canis lupus familiaris",
+        "entlebucher",
+        "boyero de Entlebuch",
+        "bouvier de l'Entlebuch",
+    ),
+    ("Canis lupus familiaris", "boxer", "bóxer", "boxer"),
+    (
+        "Canis lupus familiaris",
+        "bullmastiff",
+        "bullmastiff",
+        "bullmastiff",
+    ),
+    (
+        "Canis lupus familiaris",
+        "mastiff tibet",
+        "mastín tibetano",
+        "dogue du Tibet",
+    ),
+    (
+        "Canis lupus familiaris",
+        "french bulldog",
+        "bulldog francés",
+        "bouledogue français",
+    ),
+    (
+        "Canis lupus familiaris",
+        "great dane",
+        "gran danés",
+        "dogue allemand",
+    ),
+    (
+        "Canis lupus familiaris",
+        "saint bernard",
+        "san bernardo",
+        "saint-bernard",
+    ),
+    (
+        "Canis lupus familiaris",
+        "anjing eskimo",
+        "perro esquimal",
+        "chien esquimau",
+    ),
+    (
+        "Canis lupus familiaris",
+        "alaskan malamute",
+        "malamute de Alaska",
+        "malamute de l'Alaska",
+    ),
+    (
+        "Canis lupus familiaris",
+        "siberian husky",
+        "husky siberiano",
+        "husky sibérien",
+    ),
+    (
+        "Canis lupus familiaris",
+        "dalmatian",
+        "dálmata",
+        "dalmatien",
+    ),
+    (
+        "Canis lupus familiaris",
+        "affenpinscher",
+        "affenpinscher",
+        "affenpinscher",
+    ),
+    ("Canis lupus familiaris", "basenji", "basenji", "basenji"),
+    ("Canis lupus familiaris", "pug", "carlino", "carlin"),
+    (
+        "Canis lupus familiaris",
+        "leonberger",
+        "leonberger",
+        "leonberg",
+    ),
+    (
+        "Canis lupus familiaris",
+        "newfoundland",
+        "terranova",
+        "terre-neuve",
+    ),
+    (
+        "Canis lupus familiaris",
+        "great pyrenees",
+        "perro de montaña de los Pirineos",
+        "montagne des Pyrénées",
+    ),
+    ("Canis lupus familiaris", "samoyed", "samoyedo", "samoyède"),
+    (
+        "Canis lupus familiaris",
+        "pomeranian",
+        "pomerania",
+        "loulou de Poméranie",
+    ),
+    (
+        "Canis lupus familiaris",
+        "chow chow",
+        "chow chow",
+        "chow-chow",
+    ),
+    ("Canis lupus familiaris", "keeshond", "keeshond", "keeshond"),
+    (
+        "Canis lupus familiaris",
+        "griffon brabançon",
+        "grifón de Brabante",
+        "petit brabançon",
+    ),
+    (
+        "Canis lupus familiaris",
+        "welsh corgi pembroke",
+        "corgi galés de Pembroke",
+        "welsh corgi pembroke",
+    ),
+    (
+        "Canis lupus familiaris",
+        "welsh corgi cardigan",
+        "corgi galés de Cardigan",
+        "welsh corgi cardigan",
+    ),
+    (
+        "Canis lupus familiaris",
+        "pudel toy",
+        "caniche toy",
+        "caniche toy",
+    ),
+    (
+        "Canis lupus familiaris",
+        "pudel mini",
+        "caniche miniatura",
+        "caniche nain",
+    ),
+    (
+        "Canis lupus familiaris",
+        "pudel standar",
+        "caniche estándar",
+        "caniche royal",
+    ),
+    (
+        "Canis lupus familiaris",
+        "anjing tak berbulu meksiko",
+        "xoloitzcuintle",
+        "chien nu du Mexique",
+    ),
+    ("Canis lupus", "serigala kayu", "lobo gris", "loup gris"),
+    (
+        "Canis lupus arctos",
+        "serigala putih",
+        "lobo blanco",
+        "loup blanc",
+    ),
+    ("Canis rufus", "serigala merah", "lobo rojo", "loup roux"),
+    ("Canis latrans", "koyote", "coyote", "coyote"),
+    ("Canis lupus dingo", "dingo", "dingo", "dingo"),
+    ("Cuon alpinus", "ajag", "cuón", "dhole"),
+    ("Lycaon pictus", "anjing liar afrika", "licaón", "lycaon"),
+    ("Hyaenidae", "hiena", "hiena", "hyène"),
+    ("Vulpes vulpes", "rubah merah", "zorro rojo", "renard roux"),
+    (
+        "Vulpes macrotis",
+        "rubah kit",
+        "zorro norteño",
+        "renard nain",
+    ),
+    (
+        "Vulpes lagopus",
+        "rubah arktik",
+        "zorro ártico",
+        "renard polaire",
+    ),
+    (
+        "Urocyon cinereoargenteus",
+        "rubah abu-abu",
+        "zorro gris",
+        "renard gris",
+    ),
+    (
+        "Felis catus",
+        "kucing belang",
+        "gato atigrado",
+        "chat tigré",
+    ),
+    ("Felis catus", "kucing loreng", "gato rayado", "chat rayé"),
+    ("Felis catus", "kucing persia", "gato persa", "chat persan"),
+    ("Felis catus", "kucing siam", "gato siamés", "chat siamois"),
+    (
+        "Felis catus",
+        "kucing mesir",
+        "gato egipcio",
+        "chat égyptien",
+    ),
+    ("Puma concolor", "puma", "puma", "puma"),
+    ("Lynx", "lynx", "lince", "lynx"),
+    ("Panthera pardus", "macan tutul", "leopardo", "léopard"),
+    (
+        "Panthera uncia",
+        "macan tutul salju",
+        "leopardo de las nieves",
+        "panthère des neiges",
+    ),
+    ("Panthera onca", "jaguar", "jaguar", "jaguar"),
+    ("Panthera leo", "singa", "león", "lion"),
+    ("Panthera tigris", "harimau", "tigre", "tigre"),
+    ("Acinonyx jubatus", "cheetah", "guepardo", "guépard"),
+    ("Ursus arctos", "beruang cokelat", "oso pardo", "ours brun"),
+    (
+        "Ursus americanus",
+        "beruang hitam amerika",
+        "oso negro americano",
+        "ours noir",
+    ),
+    (
+        "Ursus maritimus",
+        "beruang kutub",
+        "oso polar",
+        "ours blanc",
+    ),
+    (
+        "Melursus ursinus",
+        "beruang sloth",
+        "oso bezudo",
+        "ours lippu",
+    ),
+    ("Herpestidae", "garangan", "mangosta", "mangouste"),
+    ("Suricata suricatta", "meerkat", "suricata", "suricate"),
+    (
+        "Cicindelinae",
+        "kumbang harimau",
+        "escarabajo tigre",
+        "cicindèle",
+    ),
+    ("Coccinellidae", "kepik", "mariquita", "coccinelle"),
+    (
+        "Carabidae",
+        "kumbang tanah",
+        "escarabajo de tierra",
+        "carabe",
+    ),
+    (
+        "Cerambycidae",
+        "kumbang sungut panjang",
+        "escarabajo longicornio",
+        "longicorne",
+    ),
+    (
+        "Chrysomelidae",
+        "kumbang daun",
+        "escarabajo de la hoja",
+        "chrysomèle",
+    ),
+    (
+        "Scarabaeinae",
+        "kumbang kotoran",
+        "escarabajo pelotero",
+        "bousier",
+    ),
+    (
+        "Dynastinae",
+        "kumbang badak",
+        "escarabajo rinoceronte",
+        "scarabée rhinocéros",
+    ),
+    ("Curculionoidea", "kumbang moncong", "gorgojo", "charançon"),
+    ("Diptera", "lalat", "mosca", "mouche"),
+    ("Apis mellifera", "lebah", "abeja", "abeille"),
+    ("Formicidae", "semut", "hormiga", "fourmi"),
+    ("Caelifera", "belalang", "saltamontes", "criquet"),
+    ("Gryllidae", "jangkrik", "grillo", "grillon"),
+    ("Phasmatodea", "serangga tongkat", "insecto palo", "phasme"),
+    ("Blattodea", "kecoa", "cucaracha", "cafard"),
+    ("Mantodea", "belalang sembah", "mantis", "mante"),
+    ("Cicadidae", "tonggeret", "cigarra", "cigale"),
+    ("Cicadellidae", "wereng daun", "chicharrita", "cicadelle"),
+    ("Chrysopidae", "undur-undur hijau", "crisopa", "chrysope"),
+    ("Anisoptera", "capung", "libélula", "libellule"),
+    (
+        "Zygoptera",
+        "capung jarum",
+        "caballito del diablo",
+        "demoiselle",
+    ),
+    (
+        "Vanessa atalanta",
+        "kupu-kupu admiral",
+        "vanesa roja",
+        "vulcain",
+    ),
+    (
+        "Aphantopus hyperantus",
+        "kupu-kupu ringlet",
+        "sortijitas",
+        "tristan",
+    ),
+    (
+        "Danaus plexippus",
+        "kupu-kupu raja",
+        "mariposa monarca",
+        "monarque",
+    ),
+    (
+        "Pieris rapae",
+        "kupu-kupu kubis",
+        "blanquita de la col",
+        "piéride de la rave",
+    ),
+    ("Colias", "kupu-kupu belerang", "colias", "soufré"),
+    ("Lycaenidae", "kupu-kupu biru", "licénido", "lycène"),
+    (
+        "Asteroidea",
+        "bintang laut",
+        "estrella de mar",
+        "étoile de mer",
+    ),
+    ("Echinoidea", "bulu babi", "erizo de mar", "oursin"),
+    (
+        "Holothuroidea",
+        "teripang",
+        "pepino de mar",
+        "concombre de mer",
+    ),
+    (
+        "Sylvilagus",
+        "kelinci hutan",
+        "conejo de rabo blanco",
+        "lapin à queue blanche",
+    ),
+    ("Lepus", "terwelu", "liebre", "lièvre"),
+    (
+        "Oryctolagus cuniculus",
+        "kelinci angora",
+        "conejo de angora",
+        "lapin angora",
+    ),
+    ("Cricetinae", "hamster", "hámster", "hamster"),
+    ("Hystricidae", "landak", "puercoespín", "porc-épic"),
+    (
+        "Sciurus niger",
+        "tupai rubah",
+        "ardilla zorro",
+        "écureuil fauve",
+    ),
+    ("Marmota", "marmut", "marmota", "marmotte"),
+    ("Castor", "berang-berang", "castor", "castor"),
+    ("Cavia porcellus", "marmot", "cobaya", "cochon d'Inde"),
+    (
+        "Equus ferus caballus",
+        "kuda coklat kemerahan",
+        "caballo alazán",
+        "cheval alezan",
+    ),
+    ("Equus quagga", "zebra", "cebra", "zèbre"),
+    ("Sus scrofa domesticus", "babi", "cerdo", "cochon"),
+    ("Sus scrofa", "babi hutan", "jabalí", "sanglier"),
+    (
+        "Phacochoerus africanus",
+        "babi hutan afrika",
+        "facóquero",
+        "phacochère",
+    ),
+    (
+        "Hippopotamus amphibius",
+        "kuda nil",
+        "hipopótamo",
+        "hippopotame",
+    ),
+    ("Bos taurus", "sapi", "buey", "bœuf"),
+    (
+        "Bubalus bubalis",
+        "kerbau",
+        "búfalo de agua",
+        "buffle d'eau",
+    ),
+    ("Bison", "bison", "bisonte", "bison"),
+    ("Ovis aries", "domba jantan", "carnero", "bélier"),
+    (
+        "Ovis canadensis",
+        "domba bighorn",
+        "borrego cimarrón",
+        "mouflon canadien",
+    ),
+    ("Capra ibex", "ibex", "íbice", "bouquetin"),
+    ("Alcelaphus buselaphus", "hartebeest", "búbalo", "bubale"),
+    ("Aepyceros melampus", "impala", "impala", "impala"),
+    ("Gazella", "kijang", "gacela", "gazelle"),
+    (
+        "Camelus dromedarius",
+        "unta arab",
+        "dromedario",
+        "dromadaire",
+    ),
+    ("Lama glama", "llama", "llama", "lama"),
+    ("Mustela", "musang", "comadreja", "belette"),
+    ("Neovison vison", "cerpelai", "visón", "vison"),
+    ("Mustela putorius", "sigung eropa", "turón", "putois"),
+    (
+        "Mustela nigripes",
+        "musang kaki hitam",
+        "hurón de patas negras",
+        "putois d'Amérique",
+    ),
+    ("Lutrinae", "berang-berang air", "nutria", "loutre"),
+    ("Mephitidae", "sigung", "mofeta", "mouffette"),
+    ("Meles meles", "luak", "tejón", "blaireau"),
+    ("Dasypodidae", "armadilo", "armadillo", "tatou"),
+    (
+        "Bradypus",
+        "kukang tiga jari",
+        "perezoso de tres dedos",
+        "paresseux tridactyle",
+    ),
+    ("Pongo", "orang utan", "orangután", "orang-outan"),
+    ("Gorilla", "gorila", "gorila", "gorille"),
+    ("Pan troglodytes", "simpanse", "chimpancé", "chimpanzé"),
+    ("Hylobates", "owa", "gibón", "gibbon"),
+    ("Symphalangus syndactylus", "siamang", "siamang", "siamang"),
+    (
+        "Cercopithecus",
+        "monyet guenon",
+        "cercopiteco",
+        "cercopithèque",
+    ),
+    ("Erythrocebus patas", "monyet patas", "mono patas", "patas"),
+    ("Papio", "babun", "babuino", "babouin"),
+    ("Macaca", "monyet kera", "macaco", "macaque"),
+    ("Semnopithecus", "lutung", "langur", "langur"),
+    ("Colobus", "monyet kolobus", "colobo", "colobe"),
+    ("Nasalis larvatus", "bekantan", "mono narigudo", "nasique"),
+    ("Callithrix", "marmoset", "tití", "ouistiti"),
+    ("Cebus", "monyet kapusin", "capuchino", "capucin"),
+    (
+        "Alouatta",
+        "monyet pelolong",
+        "mono aullador",
+        "singe hurleur",
+    ),
+    ("Callicebus", "monyet titi", "tití", "titi"),
+    ("Ateles", "monyet laba-laba", "mono araña", "atèle"),
+    ("Saimiri", "monyet tupai", "mono ardilla", "saïmiri"),
+    (
+        "Lemur catta",
+        "lemur ekor cincin",
+        "lémur de cola anillada",
+        "maki catta",
+    ),
+    ("Indri indri", "indri", "indri", "indri"),
+    (
+        "Elephas maximus",
+        "gajah asia",
+        "elefante asiático",
+        "éléphant d'Asie",
+    ),
+    (
+        "Loxodonta africana",
+        "gajah afrika",
+        "elefante africano",
+        "éléphant d'Afrique",
+    ),
+    ("Ailurus fulgens", "panda merah", "panda rojo", "panda roux"),
+    (
+        "Ailuropoda melanoleuca",
+        "panda raksasa",
+        "panda gigante",
+        "panda géant",
+    ),
+    ("Thyrsites atun", "ikan snoek", "sierra", "thyrsite"),
+    ("Anguilliformes", "belut", "anguila", "anguille"),
+    (
+        "Oncorhynchus kisutch",
+        "salmon coho",
+        "salmón plateado",
+        "saumon coho",
+    ),
+    (
+        "Holacanthus tricolor",
+        "ikan kepe-kepe rock beauty",
+        "isabelita medioluto",
+        "demoiselle beauté",
+    ),
+    (
+        "Amphiprioninae",
+        "ikan badut",
+        "pez payaso",
+        "poisson-clown",
+    ),
+    ("Acipenseridae", "ikan sturgeon", "esturión", "esturgeon"),
+    ("Lepisosteidae", "ikan gar", "pejelagarto", "lépisosté"),
+    ("Pterois", "ikan lepu ayam", "pez león", "poisson-lion"),
+    (
+        "Tetraodontidae",
+        "ikan buntal",
+        "pez globo",
+        "poisson-globe",
+    ),
+];
+
+/// The Indonesian, Spanish and French common names of the ImageNet classes
+/// that are not animals, indexed like `SYNSETS` from the first one after the
+/// animals.
+static OBJECT_NAMES: [(&str, &str, &str); 602] = [
+    ("sempoa", "ábaco", "boulier"),
+    ("abaya", "abaya", "abaya"),
+    ("toga akademik", "toga académica", "toge universitaire"),
+    ("akordeon", "acordeón", "accordéon"),
+    ("gitar akustik", "guitarra acústica", "guitare acoustique"),
+    ("kapal induk", "portaaviones", "porte-avions"),
+    ("pesawat penumpang", "avión de pasajeros", "avion de ligne"),
+    ("kapal udara", "dirigible", "dirigeable"),
+    ("altar", "altar", "autel"),
+    ("ambulans", "ambulancia", "ambulance"),
+    ("kendaraan amfibi", "vehículo anfibio", "véhicule amphibie"),
+    ("jam analog", "reloj analógico", "horloge analogique"),
+    ("peternakan lebah", "colmenar", "rucher"),
+    ("celemek", "delantal", "tablier"),
+    ("tempat sampah", "cubo de basura", "poubelle"),
+    ("senapan serbu", "fusil de asalto", "fusil d'assaut"),
+    ("ransel", "mochila", "sac à dos"),
+    ("toko roti", "panadería", "boulangerie"),
+    ("balok keseimbangan", "barra de equilibrio", "poutre"),
+    ("balon", "globo", "ballon"),
+    ("pulpen", "bolígrafo", "stylo à bille"),
+    ("plester luka", "tirita", "pansement"),
+    ("banjo", "banjo", "banjo"),
+    ("pegangan tangga", "pasamanos", "rampe d'escalier"),
+    ("barbel", "barra de pesas", "barre d'haltères"),
+    ("kursi cukur", "sillón de barbero", "fauteuil de barbier"),
+    ("pangkas rambut", "barbería", "salon de coiffure"),
+    ("lumbung", "granero", "grange"),
+    ("barometer", "barómetro", "baromètre"),
+    ("tong", "barril", "tonneau"),
+    ("gerobak dorong", "carretilla", "brouette"),
+    ("bola bisbol", "pelota de béisbol", "balle de baseball"),
+    ("bola basket", "pelota de baloncesto", "ballon de basket"),
+    ("keranjang bayi", "moisés", "berceau en osier"),
+    ("bassoon", "fagot", "basson"),
+    ("topi renang", "gorro de baño", "bonnet de bain"),
+    ("handuk mandi", "toalla de baño", "serviette de bain"),
+    ("bak mandi", "bañera", "baignoire"),
+    ("mobil station wagon", "ranchera", "break"),
+    ("mercusuar", "faro", "phare"),
+    ("gelas kimia", "vaso de precipitados", "bécher"),
+    ("topi bulu beruang", "gorro de piel de oso", "bonnet à poil"),
+    ("botol bir", "botella de cerveza", "bouteille de bière"),
+    ("gelas bir", "vaso de cerveza", "verre à bière"),
+    ("menara lonceng", "espadaña", "clocheton"),
+    ("oto", "babero", "bavoir"),
+    ("sepeda tandem", "tándem", "tandem"),
+    ("bikini", "bikini", "bikini"),
+    ("map binder", "carpeta de anillas", "classeur"),
+    ("teropong", "prismáticos", "jumelles"),
+    ("rumah burung", "casita para pájaros", "nichoir"),
+    ("rumah perahu", "cobertizo para botes", "hangar à bateaux"),
+    ("kereta luncur bobsled", "bobsleigh", "bobsleigh"),
+    ("dasi bolo", "corbata de lazo", "cravate-ficelle"),
+    ("topi bonnet", "gorro", "capote"),
+    ("rak buku", "librería", "bibliothèque meuble"),
+    ("toko buku", "tienda de libros", "librairie"),
+    ("tutup botol", "chapa", "capsule"),
+    ("busur panah", "arco", "arc"),
+    ("dasi kupu-kupu", "pajarita", "nœud papillon"),
+    ("plakat kuningan", "placa de latón", "plaque de laiton"),
+    ("beha", "sujetador", "soutien-gorge"),
+    ("pemecah gelombang", "rompeolas", "brise-lames"),
+    ("pelindung dada", "peto", "plastron"),
+    ("sapu", "escoba", "balai"),
+    ("ember", "cubo", "seau"),
+    ("gesper", "hebilla", "boucle"),
+    ("rompi antipeluru", "chaleco antibalas", "gilet pare-balles"),
+    ("kereta peluru", "tren bala", "train à grande vitesse"),
+    ("toko daging", "carnicería", "boucherie"),
+    ("taksi", "taxi", "taxi"),
+    ("kuali besar", "caldero", "chaudron"),
+    ("lilin", "vela", "bougie"),
+    ("meriam", "cañón", "canon"),
+    ("kano", "canoa", "canoë"),
+    ("pembuka kaleng", "abrelatas", "ouvre-boîte"),
+    ("kardigan", "cárdigan", "cardigan"),
+    ("spion mobil", "retrovisor", "rétroviseur"),
+    ("komidi putar", "tiovivo", "manège"),
+    (
+        "peti perkakas tukang kayu",
+        "caja de herramientas de carpintero",
+        "caisse à outils de menuisier",
+    ),
+    ("karton", "cartón", "carton"),
+    ("roda mobil", "rueda de coche", "roue de voiture"),
+    (
+        "anjungan tunai",
+        "cajero automático",
+        "distributeur de billets",
+    ),
+    ("kaset", "casete", "cassette"),
+    (
+        "pemutar kaset",
+        "reproductor de casetes",
+        "lecteur de cassettes",
+    ),
+    ("kastil", "castillo", "château fort"),
+    ("katamaran", "catamarán", "catamaran"),
+    ("pemutar cd", "reproductor de cd", "lecteur cd"),
+    ("selo", "violonchelo", "violoncelle"),
+    ("telepon seluler", "teléfono móvil", "téléphone portable"),
+    ("rantai", "cadena", "chaîne"),
+    ("pagar kawat", "valla de tela metálica", "grillage"),
+    ("baju zirah rantai", "cota de malla", "cotte de mailles"),
+    ("gergaji rantai", "motosierra", "tronçonneuse"),
+    ("peti", "baúl", "coffre"),
+    ("lemari laci", "cómoda alta", "chiffonnier"),
+    ("lonceng angin", "carillón", "carillon"),
+    ("lemari porselen", "vitrina", "vaisselier"),
+    (
+        "kaus kaki natal",
+        "calcetín de navidad",
+        "chaussette de noël",
+    ),
+    ("gereja", "iglesia", "église"),
+    ("bioskop", "cine", "cinéma"),
+    ("golok daging", "cuchilla de carnicero", "couperet"),
+    (
+        "rumah tebing",
+        "vivienda en acantilado",
+        "habitation troglodyte",
+    ),
+    ("jubah", "capa", "cape"),
+    ("bakiak", "zueco", "sabot"),
+    ("pengocok koktail", "coctelera", "shaker"),
+    ("mug kopi", "taza de café", "mug"),
+    ("teko kopi", "cafetera", "cafetière"),
+    ("gulungan", "espiral", "spirale"),
+    (
+        "gembok kombinasi",
+        "candado de combinación",
+        "cadenas à combinaison",
+    ),
+    (
+        "papan ketik komputer",
+        "teclado de ordenador",
+        "clavier d'ordinateur",
+    ),
+    ("toko permen", "confitería", "confiserie"),
+    (
+        "kapal kontainer",
+        "buque portacontenedores",
+        "porte-conteneurs",
+    ),
+    ("mobil konvertibel", "descapotable", "cabriolet"),
+    ("pembuka tutup botol", "sacacorchos", "tire-bouchon"),
+    ("kornet", "corneta", "cornet à pistons"),
+    ("sepatu bot koboi", "bota vaquera", "santiag"),
+    ("topi koboi", "sombrero vaquero", "chapeau de cow-boy"),
+    ("buaian", "cuna mecedora", "berceau"),
+    ("derek", "grúa", "grue"),
+    ("helm balap", "casco de motorista", "casque de moto"),
+    ("peti kayu", "caja de madera", "caisse"),
+    ("boks bayi", "cuna", "lit de bébé"),
+    ("panci masak lambat", "olla de cocción lenta", "mijoteuse"),
+    ("bola kroket", "bola de croquet", "boule de croquet"),
+    ("kruk", "muleta", "béquille"),
+    ("baju zirah dada", "coraza", "cuirasse"),
+    ("bendungan", "presa", "barrage"),
+    ("meja tulis", "escritorio", "bureau"),
+    (
+        "komputer desktop",
+        "ordenador de sobremesa",
+        "ordinateur de bureau",
+    ),
+    ("telepon putar", "teléfono de disco", "téléphone à cadran"),
+    ("popok", "pañal", "couche"),
+    ("jam digital", "reloj digital", "horloge numérique"),
+    (
+        "jam tangan digital",
+        "reloj de pulsera digital",
+        "montre numérique",
+    ),
+    ("meja makan", "mesa de comedor", "table de salle à manger"),
+    ("lap piring", "paño de cocina", "torchon"),
+    ("mesin pencuci piring", "lavavajillas", "lave-vaisselle"),
+    ("rem cakram", "freno de disco", "frein à disque"),
+    ("dermaga kapal", "muelle", "quai"),
+    (
+        "kereta luncur anjing",
+        "trineo de perros",
+        "traîneau à chiens",
+    ),
+    ("kubah", "cúpula", "dôme"),
+    ("keset", "felpudo", "paillasson"),
+    (
+        "anjungan pengeboran",
+        "plataforma petrolífera",
+        "plate-forme de forage",
+    ),
+    ("drum", "tambor", "tambour"),
+    ("stik drum", "baqueta", "baguette de tambour"),
+    ("dumbel", "mancuerna", "haltère"),
+    ("oven belanda", "olla holandesa", "cocotte en fonte"),
+    ("kipas angin", "ventilador", "ventilateur"),
+    ("gitar listrik", "guitarra eléctrica", "guitare électrique"),
+    (
+        "lokomotif listrik",
+        "locomotora eléctrica",
+        "locomotive électrique",
+    ),
+    ("rak hiburan", "mueble de televisión", "meuble télé"),
+    ("amplop", "sobre", "enveloppe"),
+    ("mesin espreso", "cafetera exprés", "machine à expresso"),
+    ("bedak", "polvos faciales", "poudre pour le visage"),
+    ("boa bulu", "boa de plumas", "boa de plumes"),
+    ("lemari arsip", "archivador", "classeur à tiroirs"),
+    ("kapal pemadam kebakaran", "barco bomba", "bateau-pompe"),
+    (
+        "mobil pemadam kebakaran",
+        "camión de bomberos",
+        "camion de pompiers",
+    ),
+    (
+        "tirai perapian",
+        "pantalla de chimenea",
+        "pare-feu de cheminée",
+    ),
+    ("tiang bendera", "mástil", "mât de drapeau"),
+    ("seruling", "flauta", "flûte"),
+    ("kursi lipat", "silla plegable", "chaise pliante"),
+    (
+        "helm sepak bola amerika",
+        "casco de fútbol americano",
+        "casque de football américain",
+    ),
+    ("forklif", "carretilla elevadora", "chariot élévateur"),
+    ("air mancur", "fuente", "fontaine"),
+    ("pena tinta", "pluma estilográfica", "stylo-plume"),
+    (
+        "ranjang bertiang empat",
+        "cama con dosel",
+        "lit à baldaquin",
+    ),
+    (
+        "gerbong barang",
+        "vagón de mercancías",
+        "wagon de marchandises",
+    ),
+    ("horn prancis", "trompa", "cor d'harmonie"),
+    ("wajan", "sartén", "poêle"),
+    ("mantel bulu", "abrigo de piel", "manteau de fourrure"),
+    ("truk sampah", "camión de basura", "camion-poubelle"),
+    ("masker gas", "máscara antigás", "masque à gaz"),
+    ("pompa bensin", "surtidor de gasolina", "pompe à essence"),
+    ("piala", "copa", "verre à pied"),
+    ("gokar", "kart", "kart"),
+    ("bola golf", "pelota de golf", "balle de golf"),
+    ("mobil golf", "carrito de golf", "voiturette de golf"),
+    ("gondola", "góndola", "gondole"),
+    ("gong", "gong", "gong"),
+    ("gaun", "vestido largo", "robe longue"),
+    ("piano besar", "piano de cola", "piano à queue"),
+    ("rumah kaca", "invernadero", "serre"),
+    ("gril radiator", "calandra", "calandre"),
+    ("toko kelontong", "tienda de comestibles", "épicerie"),
+    ("guillotine", "guillotina", "guillotine"),
+    ("jepit rambut", "pasador", "barrette"),
+    ("semprotan rambut", "laca", "laque"),
+    ("kendaraan setengah rantai", "semioruga", "semi-chenillé"),
+    ("palu", "martillo", "marteau"),
+    ("keranjang anyaman", "cesta con tapa", "panier à couvercle"),
+    ("pengering tangan", "secador de manos", "sèche-mains"),
+    (
+        "komputer genggam",
+        "ordenador de bolsillo",
+        "assistant personnel",
+    ),
+    ("sapu tangan", "pañuelo", "mouchoir"),
+    ("hard disk", "disco duro", "disque dur"),
+    ("harmonika", "armónica", "harmonica"),
+    ("harpa", "arpa", "harpe"),
+    ("mesin panen", "cosechadora", "moissonneuse"),
+    ("kapak kecil", "hacha de mano", "hachette"),
+    ("sarung pistol", "pistolera", "étui de pistolet"),
+    ("home theater", "cine en casa", "home cinéma"),
+    ("sarang lebah madu", "panal", "rayon de miel"),
+    ("kait", "gancho", "crochet"),
+    ("rok kurungan", "miriñaque", "crinoline"),
+    ("palang tunggal", "barra fija", "barre fixe"),
+    ("kereta kuda", "carro de caballos", "charrette à cheval"),
+    ("jam pasir", "reloj de arena", "sablier"),
+    ("ipod", "ipod", "ipod"),
+    ("setrika", "plancha", "fer à repasser"),
+    (
+        "lentera labu",
+        "calabaza de halloween",
+        "citrouille d'halloween",
+    ),
+    ("celana jin", "vaqueros", "jean"),
+    ("jip", "jeep", "jeep"),
+    ("kaus jersey", "camiseta", "maillot de sport"),
+    ("puzzle jigsaw", "rompecabezas", "puzzle"),
+    ("becak", "rickshaw", "pousse-pousse"),
+    ("joystick", "joystick", "manette de jeu"),
+    ("kimono", "kimono", "kimono"),
+    ("pelindung lutut", "rodillera", "genouillère"),
+    ("simpul", "nudo", "nœud"),
+    ("jas lab", "bata de laboratorio", "blouse de laboratoire"),
+    ("sendok sayur", "cucharón", "louche"),
+    ("kap lampu", "pantalla de lámpara", "abat-jour"),
+    ("laptop", "portátil", "ordinateur portable"),
+    ("mesin pemotong rumput", "cortacésped", "tondeuse à gazon"),
+    ("tutup lensa", "tapa de objetivo", "bouchon d'objectif"),
+    ("pembuka surat", "abrecartas", "coupe-papier"),
+    ("perpustakaan", "biblioteca", "bibliothèque"),
+    ("sekoci", "bote salvavidas", "canot de sauvetage"),
+    ("pemantik", "mechero", "briquet"),
+    ("limusin", "limusina", "limousine"),
+    ("kapal samudra", "transatlántico", "paquebot"),
+    ("lipstik", "pintalabios", "rouge à lèvres"),
+    ("sepatu loafer", "mocasín", "mocassin"),
+    ("losion", "loción", "lotion"),
+    ("pengeras suara", "altavoz", "haut-parleur"),
+    ("kaca pembesar", "lupa", "loupe"),
+    ("penggergajian kayu", "aserradero", "scierie"),
+    ("kompas", "brújula", "boussole"),
+    ("tas surat", "saca de correo", "sac postal"),
+    ("kotak surat", "buzón", "boîte aux lettres"),
+    ("baju renang", "bañador", "maillot de bain"),
+    ("baju senam", "malla", "justaucorps"),
+    ("tutup lubang got", "tapa de alcantarilla", "plaque d'égout"),
+    ("marakas", "maraca", "maracas"),
+    ("marimba", "marimba", "marimba"),
+    ("topeng", "máscara", "masque"),
+    ("batang korek api", "cerilla", "allumette"),
+    ("tiang maypole", "mayo", "arbre de mai"),
+    ("labirin", "laberinto", "labyrinthe"),
+    ("gelas takar", "taza medidora", "verre doseur"),
+    ("lemari obat", "botiquín", "armoire à pharmacie"),
+    ("megalit", "megalito", "mégalithe"),
+    ("mikrofon", "micrófono", "microphone"),
+    ("microwave", "microondas", "micro-ondes"),
+    ("seragam militer", "uniforme militar", "uniforme militaire"),
+    ("kaleng susu", "lechera", "bidon à lait"),
+    ("minibus", "microbús", "minibus"),
+    ("rok mini", "minifalda", "minijupe"),
+    ("minivan", "monovolumen", "monospace"),
+    ("rudal", "misil", "missile"),
+    ("sarung tangan jempol", "manopla", "moufle"),
+    ("mangkuk adonan", "bol para mezclar", "saladier"),
+    ("rumah mobil", "casa prefabricada", "mobile home"),
+    ("model t", "ford t", "ford t"),
+    ("modem", "módem", "modem"),
+    ("biara", "monasterio", "monastère"),
+    ("monitor", "monitor", "moniteur"),
+    ("moped", "ciclomotor", "cyclomoteur"),
+    ("lumpang", "mortero", "mortier"),
+    ("topi wisuda", "birrete", "mortier universitaire"),
+    ("masjid", "mezquita", "mosquée"),
+    ("kelambu", "mosquitera", "moustiquaire"),
+    ("skuter", "escúter", "scooter"),
+    ("sepeda gunung", "bicicleta de montaña", "vélo tout terrain"),
+    ("tenda gunung", "tienda de montaña", "tente de montagne"),
+    ("mouse komputer", "ratón", "souris d'ordinateur"),
+    ("perangkap tikus", "ratonera", "tapette à souris"),
+    (
+        "truk pindahan",
+        "camión de mudanzas",
+        "camion de déménagement",
+    ),
+    ("berangus", "bozal", "muselière"),
+    ("paku", "clavo", "clou"),
+    ("penyangga leher", "collarín", "minerve"),
+    ("kalung", "collar", "collier"),
+    ("dot", "tetina", "tétine"),
+    ("buku catatan", "cuaderno", "carnet"),
+    ("obelisk", "obelisco", "obélisque"),
+    ("obo", "oboe", "hautbois"),
+    ("okarina", "ocarina", "ocarina"),
+    ("odometer", "cuentakilómetros", "compteur kilométrique"),
+    ("filter oli", "filtro de aceite", "filtre à huile"),
+    ("orgel", "órgano", "orgue"),
+    ("osiloskop", "osciloscopio", "oscilloscope"),
+    ("rok luar", "sobrefalda", "surjupe"),
+    ("gerobak sapi", "carreta de bueyes", "char à bœufs"),
+    (
+        "masker oksigen",
+        "mascarilla de oxígeno",
+        "masque à oxygène",
+    ),
+    ("bungkusan", "paquete", "paquet"),
+    ("dayung", "remo de pala", "pagaie"),
+    ("roda dayung", "rueda de paletas", "roue à aubes"),
+    ("gembok", "candado", "cadenas"),
+    ("kuas cat", "pincel", "pinceau"),
+    ("piama", "pijama", "pyjama"),
+    ("istana", "palacio", "palais"),
+    ("seruling pan", "flauta de pan", "flûte de pan"),
+    ("tisu dapur", "papel de cocina", "essuie-tout"),
+    ("parasut", "paracaídas", "parachute"),
+    ("palang sejajar", "barras paralelas", "barres parallèles"),
+    ("bangku taman", "banco del parque", "banc public"),
+    ("meteran parkir", "parquímetro", "parcmètre"),
+    (
+        "gerbong penumpang",
+        "vagón de pasajeros",
+        "voiture de voyageurs",
+    ),
+    ("teras", "patio", "patio"),
+    ("telepon umum", "teléfono público", "cabine téléphonique"),
+    ("alas patung", "pedestal", "piédestal"),
+    ("kotak pensil", "estuche", "trousse"),
+    ("rautan pensil", "sacapuntas", "taille-crayon"),
+    ("parfum", "perfume", "parfum"),
+    ("cawan petri", "placa de petri", "boîte de petri"),
+    ("mesin fotokopi", "fotocopiadora", "photocopieuse"),
+    ("pick gitar", "púa", "médiator"),
+    ("helm pickelhaube", "casco de punta", "casque à pointe"),
+    ("pagar kayu", "valla de estacas", "palissade"),
+    ("mobil pikap", "camioneta", "pick-up"),
+    ("dermaga", "embarcadero", "jetée"),
+    ("celengan babi", "hucha", "tirelire"),
+    ("botol pil", "frasco de pastillas", "pilulier"),
+    ("bantal", "almohada", "oreiller"),
+    ("bola pingpong", "pelota de ping-pong", "balle de ping-pong"),
+    ("kincir angin mainan", "molinillo", "moulinet"),
+    ("kapal bajak laut", "barco pirata", "bateau pirate"),
+    ("teko air", "jarra", "pichet"),
+    ("serut kayu", "cepillo de carpintero", "rabot"),
+    ("planetarium", "planetario", "planétarium"),
+    ("kantong plastik", "bolsa de plástico", "sac plastique"),
+    ("rak piring", "escurreplatos", "égouttoir à vaisselle"),
+    ("bajak", "arado", "charrue"),
+    ("penyedot wc", "desatascador", "ventouse"),
+    ("kamera polaroid", "cámara polaroid", "appareil polaroid"),
+    ("tiang", "poste", "poteau"),
+    ("mobil polisi", "furgón policial", "fourgon de police"),
+    ("ponco", "poncho", "poncho"),
+    ("meja biliar", "mesa de billar", "billard"),
+    ("botol soda", "botella de refresco", "bouteille de soda"),
+    ("pot bunga", "maceta", "pot de fleurs"),
+    ("roda tembikar", "torno de alfarero", "tour de potier"),
+    ("bor listrik", "taladro eléctrico", "perceuse électrique"),
+    ("sajadah", "alfombra de oración", "tapis de prière"),
+    ("printer", "impresora", "imprimante"),
+    ("penjara", "prisión", "prison"),
+    ("proyektil", "proyectil", "projectile"),
+    ("proyektor", "proyector", "projecteur"),
+    ("keping hoki", "disco de hockey", "palet"),
+    ("samsak", "saco de boxeo", "sac de frappe"),
+    ("dompet wanita", "bolso", "porte-monnaie"),
+    ("pena bulu", "pluma de ave", "plume d'oie"),
+    ("selimut perca", "edredón", "courtepointe"),
+    ("mobil balap", "coche de carreras", "voiture de course"),
+    ("raket", "raqueta", "raquette"),
+    ("radiator", "radiador", "radiateur"),
+    ("radio", "radio", "radio"),
+    ("teleskop radio", "radiotelescopio", "radiotélescope"),
+    (
+        "tong air hujan",
+        "barril de lluvia",
+        "récupérateur d'eau de pluie",
+    ),
+    ("karavan", "autocaravana", "camping-car"),
+    ("gulungan pancing", "carrete", "moulinet de pêche"),
+    ("kamera refleks", "cámara réflex", "appareil reflex"),
+    ("kulkas", "frigorífico", "réfrigérateur"),
+    ("remote", "mando a distancia", "télécommande"),
+    ("restoran", "restaurante", "restaurant"),
+    ("revolver", "revólver", "revolver"),
+    ("senapan", "rifle", "carabine"),
+    ("kursi goyang", "mecedora", "fauteuil à bascule"),
+    ("alat pemanggang putar", "asador giratorio", "rôtissoire"),
+    ("penghapus karet", "goma de borrar", "gomme"),
+    ("bola rugbi", "balón de rugby", "ballon de rugby"),
+    ("penggaris", "regla", "règle"),
+    ("sepatu lari", "zapatilla de correr", "chaussure de course"),
+    ("brankas", "caja fuerte", "coffre-fort"),
+    ("peniti", "imperdible", "épingle de sûreté"),
+    ("tempat garam", "salero", "salière"),
+    ("sandal", "sandalia", "sandale"),
+    ("sarung", "sarong", "paréo"),
+    ("saksofon", "saxofón", "saxophone"),
+    ("sarung pedang", "vaina", "fourreau"),
+    ("timbangan", "báscula", "balance"),
+    ("bus sekolah", "autobús escolar", "bus scolaire"),
+    ("sekunar", "goleta", "goélette"),
+    ("papan skor", "marcador", "tableau d'affichage"),
+    ("layar", "pantalla", "écran"),
+    ("sekrup", "tornillo", "vis"),
+    ("obeng", "destornillador", "tournevis"),
+    (
+        "sabuk pengaman",
+        "cinturón de seguridad",
+        "ceinture de sécurité",
+    ),
+    ("mesin jahit", "máquina de coser", "machine à coudre"),
+    ("perisai", "escudo", "bouclier"),
+    ("toko sepatu", "zapatería", "magasin de chaussures"),
+    ("shoji", "shoji", "shoji"),
+    (
+        "keranjang belanja",
+        "cesta de la compra",
+        "panier à provisions",
+    ),
+    (
+        "troli belanja",
+        "carrito de la compra",
+        "chariot de supermarché",
+    ),
+    ("sekop", "pala", "pelle"),
+    ("topi mandi", "gorro de ducha", "bonnet de douche"),
+    ("tirai kamar mandi", "cortina de ducha", "rideau de douche"),
+    ("ski", "esquí", "ski"),
+    ("masker ski", "pasamontañas", "cagoule"),
+    ("kantong tidur", "saco de dormir", "sac de couchage"),
+    ("mistar hitung", "regla de cálculo", "règle à calcul"),
+    ("pintu geser", "puerta corredera", "porte coulissante"),
+    ("mesin slot", "tragaperras", "machine à sous"),
+    ("snorkel", "tubo de buceo", "tuba"),
+    ("mobil salju", "moto de nieve", "motoneige"),
+    ("bajak salju", "quitanieves", "chasse-neige"),
+    (
+        "dispenser sabun",
+        "dispensador de jabón",
+        "distributeur de savon",
+    ),
+    ("bola sepak", "balón de fútbol", "ballon de football"),
+    ("kaus kaki", "calcetín", "chaussette"),
+    ("piringan surya", "disco solar", "four solaire"),
+    ("sombrero", "sombrero mexicano", "sombrero"),
+    ("mangkuk sup", "plato hondo", "bol à soupe"),
+    ("tombol spasi", "barra espaciadora", "barre d'espace"),
+    ("pemanas ruangan", "estufa eléctrica", "radiateur d'appoint"),
+    (
+        "pesawat ulang-alik",
+        "transbordador espacial",
+        "navette spatiale",
+    ),
+    ("spatula", "espátula", "spatule"),
+    ("perahu motor", "lancha motora", "hors-bord"),
+    ("jaring laba-laba", "telaraña", "toile d'araignée"),
+    ("kumparan pintal", "huso", "fuseau"),
+    ("mobil sport", "deportivo", "voiture de sport"),
+    ("lampu sorot", "foco", "projecteur de scène"),
+    ("panggung", "escenario", "scène"),
+    (
+        "lokomotif uap",
+        "locomotora de vapor",
+        "locomotive à vapeur",
+    ),
+    (
+        "jembatan lengkung baja",
+        "puente de arco de acero",
+        "pont en arc métallique",
+    ),
+    ("drum baja", "tambor metálico", "steel-drum"),
+    ("stetoskop", "estetoscopio", "stéthoscope"),
+    ("selendang", "estola", "étole"),
+    ("tembok batu", "muro de piedra", "mur de pierre"),
+    ("stopwatch", "cronómetro", "chronomètre"),
+    ("kompor", "cocina", "cuisinière"),
+    ("saringan", "colador", "passoire"),
+    ("trem", "tranvía", "tramway"),
+    ("tandu", "camilla", "brancard"),
+    ("sofa bed", "sofá cama", "canapé-lit"),
+    ("stupa", "estupa", "stupa"),
+    ("kapal selam", "submarino", "sous-marin"),
+    ("setelan jas", "traje", "costume"),
+    ("jam matahari", "reloj de sol", "cadran solaire"),
+    ("lensa pembakar", "lente ustoria", "verre ardent"),
+    ("kacamata hitam", "gafas de sol", "lunettes de soleil"),
+    ("tabir surya", "protector solar", "crème solaire"),
+    ("jembatan gantung", "puente colgante", "pont suspendu"),
+    ("kapas lidi", "bastoncillo", "coton-tige"),
+    ("sweter", "sudadera", "sweat-shirt"),
+    (
+        "celana renang",
+        "bañador de hombre",
+        "maillot de bain pour homme",
+    ),
+    ("ayunan", "columpio", "balançoire"),
+    ("sakelar", "interruptor", "interrupteur"),
+    ("suntikan", "jeringa", "seringue"),
+    ("lampu meja", "lámpara de mesa", "lampe de table"),
+    ("tank", "tanque", "char d'assaut"),
+    ("pemutar pita", "reproductor de cintas", "magnétophone"),
+    ("teko teh", "tetera", "théière"),
+    ("boneka beruang", "osito de peluche", "ours en peluche"),
+    ("televisi", "televisor", "téléviseur"),
+    ("bola tenis", "pelota de tenis", "balle de tennis"),
+    ("atap jerami", "tejado de paja", "toit de chaume"),
+    ("tirai teater", "telón", "rideau de théâtre"),
+    ("bidal", "dedal", "dé à coudre"),
+    ("mesin perontok", "trilladora", "batteuse"),
+    ("takhta", "trono", "trône"),
+    ("atap genteng", "tejado de tejas", "toit de tuiles"),
+    ("pemanggang roti", "tostadora", "grille-pain"),
+    ("toko tembakau", "estanco", "bureau de tabac"),
+    (
+        "dudukan kloset",
+        "asiento de inodoro",
+        "abattant de toilettes",
+    ),
+    ("obor", "antorcha", "torche"),
+    ("tiang totem", "tótem", "mât totémique"),
+    ("truk derek", "grúa de remolque", "dépanneuse"),
+    ("toko mainan", "juguetería", "magasin de jouets"),
+    ("traktor", "tractor", "tracteur"),
+    ("truk trailer", "camión articulado", "semi-remorque"),
+    ("nampan", "bandeja", "plateau"),
+    ("jas hujan", "gabardina", "trench-coat"),
+    ("sepeda roda tiga", "triciclo", "tricycle"),
+    ("trimaran", "trimarán", "trimaran"),
+    ("tripod", "trípode", "trépied"),
+    ("gapura kemenangan", "arco de triunfo", "arc de triomphe"),
+    ("bus listrik", "trolebús", "trolleybus"),
+    ("trombon", "trombón", "trombone"),
+    ("bak", "tina", "cuve"),
+    ("pintu putar", "torniquete", "tourniquet"),
+    (
+        "papan ketik mesin tik",
+        "teclado de máquina de escribir",
+        "clavier de machine à écrire",
+    ),
+    ("payung", "paraguas", "parapluie"),
+    ("sepeda roda satu", "monociclo", "monocycle"),
+    ("piano tegak", "piano vertical", "piano droit"),
+    ("penyedot debu", "aspiradora", "aspirateur"),
+    ("vas", "jarrón", "vase"),
+    ("langit-langit lengkung", "bóveda", "voûte"),
+    ("beludru", "terciopelo", "velours"),
+    (
+        "mesin penjual otomatis",
+        "máquina expendedora",
+        "distributeur automatique",
+    ),
+    (
+        "jubah liturgi",
+        "vestidura litúrgica",
+        "vêtement liturgique",
+    ),
+    ("jembatan viaduk", "viaducto", "viaduc"),
+    ("biola", "violín", "violon"),
+    ("bola voli", "balón de voleibol", "ballon de volley"),
+    ("cetakan wafel", "gofrera", "gaufrier"),
+    ("jam dinding", "reloj de pared", "horloge murale"),
+    ("dompet", "cartera", "portefeuille"),
+    ("lemari pakaian", "armario", "armoire"),
+    ("pesawat tempur", "avión de guerra", "avion de guerre"),
+    ("wastafel", "lavabo", "lavabo"),
+    ("mesin cuci", "lavadora", "lave-linge"),
+    ("botol minum", "botella de agua", "bouteille d'eau"),
+    ("kendi air", "jarra de agua", "cruche"),
+    ("menara air", "depósito de agua", "château d'eau"),
+    ("kendi wiski", "jarra de whisky", "cruchon de whisky"),
+    ("peluit", "silbato", "sifflet"),
+    ("wig", "peluca", "perruque"),
+    (
+        "kasa jendela",
+        "mosquitera de ventana",
+        "moustiquaire de fenêtre",
+    ),
+    ("tirai jendela", "persiana", "store"),
+    ("dasi windsor", "corbata windsor", "cravate windsor"),
+    ("botol anggur", "botella de vino", "bouteille de vin"),
+    ("sayap pesawat", "ala de avión", "aile d'avion"),
+    ("wajan cina", "wok", "wok"),
+    ("sendok kayu", "cuchara de madera", "cuillère en bois"),
+    ("wol", "lana", "laine"),
+    ("pagar zig-zag", "cerca en zigzag", "clôture en zigzag"),
+    ("bangkai kapal", "pecio", "épave"),
+    ("kapal yawl", "yola", "yole"),
+    ("yurt", "yurta", "yourte"),
+    ("situs web", "sitio web", "site web"),
+    ("buku komik", "cómic", "bande dessinée"),
+    ("teka-teki silang", "crucigrama", "mots croisés"),
+    (
+        "rambu jalan",
+        "señal de tráfico",
+        "panneau de signalisation",
+    ),
+    ("lampu lalu lintas", "semáforo", "feu de circulation"),
+    ("sampul buku", "sobrecubierta", "jaquette de livre"),
+    ("menu", "menú", "menu"),
+    ("piring", "plato", "assiette"),
+    ("guacamole", "guacamole", "guacamole"),
+    ("kaldu bening", "consomé", "consommé"),
+    ("hot pot", "olla caliente", "fondue chinoise"),
+    ("trifle", "trifle", "trifle"),
+    ("es krim", "helado", "glace"),
+    ("es loli", "polo", "glace à l'eau"),
+    ("roti prancis", "barra de pan", "baguette"),
+    ("bagel", "bagel", "bagel"),
+    ("pretzel", "pretzel", "bretzel"),
+    ("cheeseburger", "hamburguesa con queso", "cheeseburger"),
+    ("hot dog", "perrito caliente", "hot-dog"),
+    (
+        "kentang tumbuk",
+        "puré de patatas",
+        "purée de pommes de terre",
+    ),
+    ("kubis", "repollo", "chou pommé"),
+    ("brokoli", "brócoli", "brocoli"),
+    ("kembang kol", "coliflor", "chou-fleur"),
+    ("zukini", "calabacín", "courgette"),
+    ("labu spageti", "calabaza espagueti", "courge spaghetti"),
+    ("labu acorn", "calabaza bellota", "courge poivrée"),
+    ("labu butternut", "calabaza moscada", "courge musquée"),
+    ("mentimun", "pepino", "concombre"),
+    ("artichoke", "alcachofa", "artichaut"),
+    ("paprika", "pimiento", "poivron"),
+    ("kardon", "cardo", "cardon"),
+    ("jamur", "champiñón", "champignon"),
+    (
+        "apel granny smith",
+        "manzana granny smith",
+        "pomme granny smith",
+    ),
+    ("stroberi", "fresa", "fraise"),
+    ("jeruk", "naranja", "orange"),
+    ("lemon", "limón", "citron"),
+    ("buah ara", "higo", "figue"),
+    ("nanas", "piña", "ananas"),
+    ("pisang", "plátano", "banane"),
+    ("nangka", "yaca", "jacquier"),
+    ("srikaya", "chirimoya", "pomme cannelle"),
+    ("delima", "granada", "grenade"),
+    ("jerami", "heno", "foin"),
+    (
+        "karbonara",
+        "espaguetis a la carbonara",
+        "spaghetti à la carbonara",
+    ),
+    ("saus cokelat", "salsa de chocolate", "sauce au chocolat"),
+    ("adonan", "masa", "pâte"),
+    ("rolade daging", "pastel de carne", "pain de viande"),
+    ("pizza", "pizza", "pizza"),
+    ("pai daging", "pastel de pollo", "tourte"),
+    ("burrito", "burrito", "burrito"),
+    ("anggur merah", "vino tinto", "vin rouge"),
+    ("espreso", "café expreso", "expresso"),
+    ("cangkir", "taza", "tasse"),
+    ("eggnog", "ponche de huevo", "lait de poule"),
+    ("pegunungan alpen", "alpe", "alpage"),
+    ("gelembung", "burbuja", "bulle"),
+    ("tebing", "acantilado", "falaise"),
+    ("terumbu karang", "arrecife de coral", "récif corallien"),
+    ("geiser", "géiser", "geyser"),
+    ("tepi danau", "orilla del lago", "bord de lac"),
+    ("tanjung", "promontorio", "promontoire"),
+    ("gosong pasir", "banco de arena", "banc de sable"),
+    ("pantai", "orilla del mar", "bord de mer"),
+    ("lembah", "valle", "vallée"),
+    ("gunung berapi", "volcán", "volcan"),
+    ("pemain bisbol", "jugador de béisbol", "joueur de baseball"),
+    ("pengantin pria", "novio", "marié"),
+    ("penyelam skuba", "buceador", "plongeur"),
+    ("kanola", "colza", "colza"),
+    ("bunga aster", "margarita", "marguerite"),
+    (
+        "anggrek sandal kuning",
+        "zapatito de dama amarillo",
+        "sabot de vénus jaune",
+    ),
+    ("jagung", "maíz", "maïs"),
+    ("biji ek", "bellota", "gland"),
+    ("buah mawar", "escaramujo", "cynorhodon"),
+    ("kastanye kuda", "castaña de indias", "marron d'inde"),
+    ("jamur karang", "hongo coral", "clavaire"),
+    ("jamur agarik", "agárico", "agaric"),
+    ("jamur gyromitra", "colmenilla falsa", "gyromitre"),
+    ("jamur stinkhorn", "falo hediondo", "satyre puant"),
+    ("jamur bintang tanah", "estrella de tierra", "géastre"),
+    ("jamur maitake", "maitake", "poule des bois"),
+    ("jamur bolete", "boleto", "bolet"),
+    ("tongkol jagung", "mazorca", "épi de maïs"),
+    ("tisu toilet", "papel higiénico", "papier toilette"),
+];
@@ -1,155 +1,534 @@
-use crate::Classification;
+use crate::{
+    names, preprocess, taxonomy, BreedMix, BreedShare, Classification, ClassifyError,
+    ClassifyOptions, Crop, EmbedOptions, LabelInfo, ModelConfig, ModelInfo, Normalization,
+    NotAnimal, Pet,
+};
 use prost::Message;
 use std::cell::RefCell;
+use std::collections::BTreeMap;
 use tract_onnx::prelude::*;
-use reqwest::Client;
-use serde::{Deserialize, Serialize};
-use std::env;
-use dotenv::dotenv;
 
 type Model = SimplePlan<TypedFact, Box<dyn TypedOp>, Graph<TypedFact, Box<dyn TypedOp>>>;
 
-thread_local! {
-    static MODEL: RefCell<Option<Model>> = RefCell::new(None);
+/// A runnable model together with the configuration describing its input
+/// and output.
+struct LoadedModel {
+    plan: Model,
+    config: ModelConfig,
+    /// Whether the plan has a second output with the features that the
+    /// classifier head maps to the logits.
+    embeds: bool,
 }
 
-#[derive(Serialize)]
-struct CompletionRequest {
-    model: String,
-    messages: Vec<Messag>,
-    max_tokens: u32,
+thread_local! {
+    static MODELS: RefCell<BTreeMap<String, LoadedModel>> = RefCell::new(BTreeMap::new());
 }
 
-#[derive(Serialize)]
-struct Messag {
-    role: String,
-    content: String,
-}
+/// The id of the model used by `classify` and under which the embedded
+/// MobileNet model is registered.
+pub const DEFAULT_MODEL_ID: &str = "mobilenetv2";
 
-#[derive(Deserialize)]
-struct ChatResponse {
-    choices: Vec<ChatChoice>,
-}
+/// The number of labels returned when the options do not specify `top_k`.
+const DEFAULT_TOP_K: usize = 5;
 
-#[derive(Deserialize)]
-struct ChatChoice {
-    message: MessageResponse,
-}
+/// An image whose animal classes hold less probability mass than this is
+/// reported as not showing an animal.
+const NOT_ANIMAL_THRESHOLD: f32 = 0.5;
 
-#[derive(Deserialize)]
-struct MessageResponse {
-    content: String,
-}
+/// A breed distribution whose normalised entropy is at least this high is
+/// reported as a likely mixed breed.
+const MIXED_BREED_ENTROPY: f32 = 0.5;
 
+/// The ImageNet classes from "tench" to "puffer" are animals, everything
+/// after them is an object, a plant or a scene.
+const IMAGENET_ANIMALS: std::ops::Range<u32> = 0..398;
 
 /// An image classification model trained on ImageNet dataset.
 /// The model itself is not included in the source code of this example.
 /// If you see a compile error here, then download the model from:
 /// https://github.com/onnx/models/tree/main/validated/vision/classification/mobilenet
 /// See the `download_model.sh` script for details.
+/// Build without the `embedded-model` feature to leave it out of the wasm and
+/// upload a model at runtime instead.
+#[cfg(feature = "embedded-model")]
 const IMAGENET: &'static [u8] = include_bytes!("../assets/mobilenetv2-7.onnx");
 
-/// Constructs a runnable model from the serialized ONNX model in `IMAGENET`.
+/// The configuration of the ImageNet MobileNet model. The preprocessing follows
+/// https://github.com/onnx/models/tree/main/validated/vision/classification/mobilenet#preprocessing.
+pub fn imagenet_config() -> ModelConfig {
+    ModelConfig {
+        labels: LABELS.iter().map(|label| label.to_string()).collect(),
+        input_size: 224,
+        mean: vec![0.485, 0.456, 0.406],
+        std: vec![0.229, 0.224, 0.225],
+        temperature: None,
+        animal_classes: Some(IMAGENET_ANIMALS.collect()),
+        synsets: Some(taxonomy::SYNSETS.iter().map(|synset| synset.to_string()).collect()),
+        embedding_output: None,
+    }
+}
+
+/// Constructs runnable models from the serialized ONNX model in `IMAGENET` and
+/// from all models uploaded to stable memory. An uploaded model with the id
+/// `DEFAULT_MODEL_ID` replaces the embedded one.
 pub fn setup() -> TractResult<()> {
-    let bytes = bytes::Bytes::from_static(IMAGENET);
+    #[cfg(feature = "embedded-model")]
+    load(DEFAULT_MODEL_ID, IMAGENET, imagenet_config())?;
+    for (id, model) in crate::upload::stored_models() {
+        if let Err(err) = load(&id, &model.bytes, model.config) {
+            ic_cdk::println!("Failed to load model {}: {}", id, err);
+        }
+    }
+    Ok(())
+}
+
+/// Constructs a runnable model from the given serialized ONNX model and
+/// registers it under `id`, replacing any model with the same id.
+pub fn load(id: &str, bytes: &[u8], config: ModelConfig) -> TractResult<()> {
     let proto: tract_onnx::pb::ModelProto = tract_onnx::pb::ModelProto::decode(bytes)?;
-    let model = tract_onnx::onnx()
-        .model_for_proto_model(&proto)?
-        .into_optimized()?
-        .into_runnable()?;
-    MODEL.with_borrow_mut(|m| {
-        *m = Some(model);
+    let mut model = tract_onnx::onnx().model_for_proto_model(&proto)?;
+    let embedding = config
+        .embedding_output
+        .clone()
+        .or_else(|| embedding_output(&proto));
+    let embeds = match (logits_output(&proto), embedding) {
+        (Some(logits), Some(embedding)) => {
+            model.set_output_names([logits, embedding])?;
+            true
+        }
+        _ => false,
+    };
+    let plan = model.into_optimized()?.into_runnable()?;
+    MODELS.with_borrow_mut(|models| {
+        models.insert(
+            id.to_string(),
+            LoadedModel {
+                plan,
+                config,
+                embeds,
+            },
+        );
     });
     Ok(())
 }
 
-/// Runs the model on the given image and returns top three labels.
-pub fn classify(image: Vec<u8>) -> Result<Vec<Classification>, anyhow::Error> {
-    MODEL.with_borrow(|model| {
-        let model = model.as_ref().unwrap();
-        let image = image::load_from_memory(&image)?.to_rgb8();
+/// The name of the tensor with the logits of the model.
+fn logits_output(proto: &tract_onnx::pb::ModelProto) -> Option<String> {
+    Some(proto.graph.as_ref()?.output.first()?.name.clone())
+}
+
+/// Finds the tensor that the classifier head of the model is applied to, such
+/// as the 1280 pooled features of MobileNet, by following the logits back
+/// through reshapes to the last fully connected or 1x1 convolution layer.
+fn embedding_output(proto: &tract_onnx::pb::ModelProto) -> Option<String> {
+    let graph = proto.graph.as_ref()?;
+    let mut tensor = logits_output(proto)?;
+    loop {
+        let node = graph
+            .node
+            .iter()
+            .find(|node| node.output.contains(&tensor))?;
+        match node.op_type.as_str() {
+            "Reshape" | "Flatten" | "Squeeze" | "Identity" | "Dropout" => {
+                tensor = node.input.first()?.clone();
+            }
+            "Gemm" | "MatMul" | "Conv" => return node.input.first().cloned(),
+            _ => return None,
+        }
+    }
+}
+
+/// Unregisters the model with the given id. Returns false if there was none.
+pub fn unload(id: &str) -> bool {
+    MODELS.with_borrow_mut(|models| models.remove(id).is_some())
+}
 
-        // The model accepts an image of size 224x224px.
-        let image =
-            image::imageops::resize(&image, 224, 224, ::image::imageops::FilterType::Triangle);
+/// Describes all registered models.
+pub fn models() -> Vec<ModelInfo> {
+    MODELS.with_borrow(|models| {
+        models
+            .iter()
+            .map(|(id, model)| ModelInfo {
+                id: id.clone(),
+                label_count: model.config.labels.len() as u32,
+                input_size: model.config.input_size,
+                embeds: model.embeds,
+            })
+            .collect()
+    })
+}
 
-        // Preprocess the input according to
-        // https://github.com/onnx/models/tree/main/validated/vision/classification/mobilenet#preprocessing.
-        const MEAN: [f32; 3] = [0.485, 0.456, 0.406];
-        const STD: [f32; 3] = [0.229, 0.224, 0.225];
-        let tensor = tract_ndarray::Array4::from_shape_fn((1, 3, 224, 224), |(_, c, y, x)| {
-            (image[(x as u32, y as u32)][c] as f32 / 255.0 - MEAN[c]) / STD[c]
-        });
+/// The output of a model for a single class.
+struct Prediction {
+    index: usize,
+    probability: f32,
+    logit: f32,
+}
+
+/// Runs the model on the image and returns its outputs in order: the logits
+/// and, if the model embeds, the embedding.
+fn run(model: &LoadedModel, image: &[u8], crop: Crop) -> Result<Vec<Vec<f32>>, ClassifyError> {
+    let LoadedModel { plan, config, .. } = model;
+    let size = config.input_size;
+    let image = preprocess::prepare(image, size, crop)?;
+
+    let shape = (1, 3, size as usize, size as usize);
+    let tensor = tract_ndarray::Array4::from_shape_fn(shape, |(_, c, y, x)| {
+        (image[(x as u32, y as u32)][c] as f32 / 255.0 - config.mean[c]) / config.std[c]
+    });
 
-        let result = model.run(tvec!(Tensor::from(tensor).into()))?;
+    let result = plan
+        .run(tvec!(Tensor::from(tensor).into()))
+        .map_err(inference_failed)?;
 
-        let mut scores: Vec<_> = result[0]
-            .to_array_view::<f32>()?
+    result
+        .iter()
+        .map(|output| {
+            Ok(output
+                .to_array_view::<f32>()
+                .map_err(inference_failed)?
+                .iter()
+                .copied()
+                .collect())
+        })
+        .collect()
+}
+
+/// Runs the model on the image and returns a prediction for every class in
+/// the order of the model output.
+fn predict(
+    model: &LoadedModel,
+    image: Vec<u8>,
+    options: &ClassifyOptions,
+) -> Result<Vec<Prediction>, ClassifyError> {
+    let logits = run(model, &image, options.crop.unwrap_or(Crop::Center))?.swap_remove(0);
+    Ok(predictions(&model.config, logits))
+}
+
+fn predictions(config: &ModelConfig, logits: Vec<f32>) -> Vec<Prediction> {
+    let probabilities = softmax(&logits, config.temperature.unwrap_or(1.0));
+    probabilities
+        .into_iter()
+        .zip(logits)
+        .enumerate()
+        .map(|(index, (probability, logit))| Prediction {
+            index,
+            probability,
+            logit,
+        })
+        .collect()
+}
+
+fn inference_failed(err: anyhow::Error) -> ClassifyError {
+    ClassifyError::InferenceFailed {
+        message: err.to_string(),
+    }
+}
+
+/// What the classifier concluded about an image.
+pub enum Verdict {
+    /// The most probable labels that pass the filters of the options.
+    Labels(Vec<Classification>),
+    /// Animals were requested but the image most likely shows something else.
+    NotAnimal(NotAnimal),
+}
+
+/// Runs the model selected by the options on the image and returns the most
+/// probable labels that pass the filters of the options.
+///
+/// With `animals_only` set, the probabilities of the classes that are not
+/// animals are dropped and the rest is renormalised. If the dropped classes
+/// hold most of the probability mass, `Verdict::NotAnimal` is returned instead.
+pub fn classify(image: Vec<u8>, options: &ClassifyOptions) -> Result<Verdict, ClassifyError> {
+    let model_id = options.model_id.as_deref().unwrap_or(DEFAULT_MODEL_ID);
+    MODELS.with_borrow(|models| {
+        let model = models
+            .get(model_id)
+            .ok_or_else(|| model_not_loaded(model_id))?;
+        Ok(verdict(model, predict(model, image, options)?, options))
+    })
+}
+
+/// Classifies the image like `classify` and embeds it like `embed` with the
+/// default `EmbedOptions`, from a single run of the model.
+pub fn classify_and_embed(
+    image: &[u8],
+    options: &ClassifyOptions,
+) -> Result<(Verdict, Vec<f32>), ClassifyError> {
+    let model_id = options.model_id.as_deref().unwrap_or(DEFAULT_MODEL_ID);
+    MODELS.with_borrow(|models| {
+        let model = models
+            .get(model_id)
+            .ok_or_else(|| model_not_loaded(model_id))?;
+        if !model.embeds {
+            return Err(no_embedding_output(model_id));
+        }
+        let mut outputs = run(model, image, options.crop.unwrap_or(Crop::Center))?.into_iter();
+        let (Some(logits), Some(mut embedding)) = (outputs.next(), outputs.next()) else {
+            return Err(no_embedding_returned());
+        };
+        normalize(&mut embedding, Normalization::L2);
+        let predictions = predictions(&model.config, logits);
+        Ok((verdict(model, predictions, options), embedding))
+    })
+}
+
+/// Applies the filters of the options to the predictions of the model.
+fn verdict(
+    model: &LoadedModel,
+    mut predictions: Vec<Prediction>,
+    options: &ClassifyOptions,
+) -> Verdict {
+    if options.animals_only.unwrap_or(false) {
+        if let Some(animal_classes) = &model.config.animal_classes {
+            let mut is_animal = vec![false; predictions.len()];
+            for &index in animal_classes {
+                if let Some(flag) = is_animal.get_mut(index as usize) {
+                    *flag = true;
+                }
+            }
+            let (animals, others): (Vec<_>, Vec<_>) = predictions
+                .into_iter()
+                .partition(|prediction| is_animal[prediction.index]);
+            let animal_score: f32 = animals.iter().map(|p| p.probability).sum();
+            if animal_score < NOT_ANIMAL_THRESHOLD {
+                if let Some(top) = others
+                    .iter()
+                    .max_by(|a, b| a.probability.total_cmp(&b.probability))
+                {
+                    return Verdict::NotAnimal(NotAnimal {
+                        animal_score,
+                        top_label: localise(classification(&model.config, top), options),
+                    });
+                }
+            }
+            predictions = animals
+                .into_iter()
+                .map(|prediction| Prediction {
+                    probability: prediction.probability / animal_score,
+                    ..prediction
+                })
+                .collect();
+        }
+    }
+
+    let mut labels: Vec<_> = predictions
+        .iter()
+        .map(|prediction| classification(&model.config, prediction))
+        .collect();
+    if let Some(rank) = options.rollup {
+        labels = taxonomy::roll_up(labels, rank);
+    }
+
+    labels.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    let min_score = options.min_score.unwrap_or(0.0);
+    let labels = labels
+        .into_iter()
+        .take_while(|classification| classification.score >= min_score)
+        .filter(|classification| options.accepts(&classification.label))
+        .take(options.top_k.map_or(DEFAULT_TOP_K, |k| k as usize))
+        .map(|classification| localise(classification, options))
+        .collect();
+    Verdict::Labels(labels)
+}
+
+/// Estimates the breeds of a dog or a cat by restricting the predictions to
+/// the labels of that species and renormalising them. The entropy of the
+/// resulting distribution tells how sure the model is about a single breed.
+pub fn breed_mix(
+    image: Vec<u8>,
+    pet: Pet,
+    options: &ClassifyOptions,
+) -> Result<BreedMix, ClassifyError> {
+    let model_id = options.model_id.as_deref().unwrap_or(DEFAULT_MODEL_ID);
+    MODELS.with_borrow(|models| {
+        let model = models
+            .get(model_id)
+            .ok_or_else(|| model_not_loaded(model_id))?;
+        let breeds: Vec<_> = predict(model, image, options)?
             .into_iter()
-            .zip(0..)
+            .filter(|prediction| {
+                synset(&model.config, prediction.index)
+                    .as_deref()
+                    .and_then(taxonomy::lineage)
+                    .is_some_and(|lineage| lineage.species == pet.species())
+            })
             .collect();
+        if breeds.is_empty() {
+            return Err(ClassifyError::InvalidRequest {
+                message: format!("model {} has no {} breeds", model_id, pet.species()),
+            });
+        }
 
-        scores.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
-
-        let labels = scores
+        let pet_score: f32 = breeds.iter().map(|breed| breed.probability).sum();
+        let mut shares: Vec<_> = breeds
+            .iter()
+            .map(|breed| (breed, breed.probability / pet_score))
+            .collect();
+        let entropy: f32 = shares
             .iter()
-            .take(1)
-            .map(|(score, i)| Classification {
-                label: LABELS[*i as usize].to_string(),
-                score: **score,
+            .filter(|(_, share)| *share > 0.0)
+            .map(|(_, share)| -share * share.ln())
+            .sum();
+        let entropy = if shares.len() > 1 {
+            entropy / (shares.len() as f32).ln()
+        } else {
+            0.0
+        };
+
+        shares.sort_by(|a, b| b.1.total_cmp(&a.1));
+        let min_score = options.min_score.unwrap_or(0.0);
+        let breeds = shares
+            .into_iter()
+            .take_while(|(_, share)| *share >= min_score)
+            .take(options.top_k.map_or(DEFAULT_TOP_K, |k| k as usize))
+            .map(|(breed, share)| {
+                let classification = localise(classification(&model.config, breed), options);
+                BreedShare {
+                    label: classification.label,
+                    percentage: share * 100.0,
+                    synset: classification.synset,
+                }
             })
             .collect();
-        Ok(labels)
+        Ok(BreedMix {
+            breeds,
+            pet_score,
+            entropy,
+            mixed_breed_likely: entropy >= MIXED_BREED_ENTROPY,
+        })
     })
 }
 
-#[ic_cdk::query]
-fn gateway_http_request() -> String {
-    "hello".to_string()
+/// Returns the features of the image that the classifier head of the model
+/// maps to the logits, flattened to a vector and normalised as requested.
+pub fn embed(image: &[u8], options: &EmbedOptions) -> Result<Vec<f32>, ClassifyError> {
+    let model_id = options.model_id.as_deref().unwrap_or(DEFAULT_MODEL_ID);
+    let mut embedding = MODELS.with_borrow(|models| {
+        let model = models
+            .get(model_id)
+            .ok_or_else(|| model_not_loaded(model_id))?;
+        if !model.embeds {
+            return Err(no_embedding_output(model_id));
+        }
+        let mut outputs = run(model, image, options.crop.unwrap_or(Crop::Center))?;
+        outputs.pop().ok_or_else(no_embedding_returned)
+    })?;
+    normalize(
+        &mut embedding,
+        options.normalization.unwrap_or(Normalization::L2),
+    );
+    Ok(embedding)
 }
 
-#[ic_cdk::query]
-pub async fn send_http_post_request(prompt: String) -> String {
-    dotenv().ok();
-    let api_key = env::var("OPENAI_API_KEY").expect("Cannot find OPENAI API KEY");
-    let client = Client::new();
-
-    let messages = vec![
-        Messag {
-            role: "system".to_string(),
-            content: "I want you to act as a pet behaviorist. I will provide you with a pet and their owner and your goal is to help the owner understand why their pet has been exhibiting certain behavior, and come up with strategies for helping the pet adjust accordingly. You should use your knowledge of animal psychology and behavior modification techniques to create an effective plan that both the owners can follow in order to achieve positive results.".to_string(),
-        },
-        Messag {
-            role: "user".to_string(),
-            content: prompt
+fn normalize(embedding: &mut [f32], normalization: Normalization) {
+    if normalization == Normalization::L2 {
+        let norm = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            embedding.iter_mut().for_each(|x| *x /= norm);
         }
-    ];
+    }
+}
 
-    let request_body = CompletionRequest {
-        model: "gpt-3.5-turbo".to_string(),
-        messages,
-        max_tokens: 150,
-    };
+fn no_embedding_output(model_id: &str) -> ClassifyError {
+    ClassifyError::InvalidRequest {
+        message: format!("model {} has no embedding output", model_id),
+    }
+}
 
-    let response = client
-        .post("https://api.openai.com/v1/chat/completions")
-        .header("Content-Type", "application/json")
-        .header("Authorization", format!("Bearer {}", api_key))
-        .json(&request_body)
-        .send()
-        .await;
+fn no_embedding_returned() -> ClassifyError {
+    ClassifyError::InferenceFailed {
+        message: "the model returned no embedding".to_string(),
+    }
+}
+
+fn model_not_loaded(model_id: &str) -> ClassifyError {
+    ClassifyError::ModelNotLoaded {
+        model_id: model_id.to_string(),
+    }
+}
 
-    let chat_response: ChatResponse = response.expect("Error response").json().await.expect("Error parsing");
+fn classification(config: &ModelConfig, prediction: &Prediction) -> Classification {
+    let synset = synset(config, prediction.index);
+    Classification {
+        label: label(config, prediction.index),
+        score: prediction.probability,
+        raw_logit: prediction.logit,
+        scientific_name: scientific_name(synset.as_deref()),
+        synset,
+    }
+}
 
-    if let Some(choice) = chat_response.choices.get(0) {
-        choice.message.content.to_string()
-    } else {
-        "error".to_string()
+/// Translates the label to the locale requested in the options, if any.
+fn localise(classification: Classification, options: &ClassifyOptions) -> Classification {
+    match &options.locale {
+        Some(locale) => names::localise(classification, locale),
+        None => classification,
     }
 }
 
+fn synset(config: &ModelConfig, index: usize) -> Option<String> {
+    config.synsets.as_ref()?.get(index).cloned()
+}
+
+fn scientific_name(synset: Option<&str>) -> Option<String> {
+    synset.and_then(names::scientific_name).map(str::to_string)
+}
+
+/// Describes every label of the model with the given id, including its
+/// taxonomy if the label has a known synset.
+pub fn labels(model_id: &str) -> Result<Vec<LabelInfo>, String> {
+    MODELS.with_borrow(|models| {
+        let model = models
+            .get(model_id)
+            .ok_or_else(|| format!("model {} is not loaded", model_id))?;
+        Ok(model
+            .config
+            .labels
+            .iter()
+            .enumerate()
+            .map(|(index, label)| {
+                let synset = synset(&model.config, index);
+                LabelInfo {
+                    index: index as u32,
+                    label: label.clone(),
+                    lineage: synset.as_deref().and_then(taxonomy::lineage),
+                    scientific_name: scientific_name(synset.as_deref()),
+                    common_names: synset.as_deref().map_or_else(Vec::new, names::common_names),
+                    synset,
+                }
+            })
+            .collect())
+    })
+}
+
+/// Turns the logits into probabilities. Logits are divided by `temperature`
+/// first, so values above one flatten the distribution of an overconfident
+/// model and values below one sharpen it.
+fn softmax(logits: &[f32], temperature: f32) -> Vec<f32> {
+    let max = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let exps: Vec<f32> = logits
+        .iter()
+        .map(|logit| ((logit - max) / temperature).exp())
+        .collect();
+    let sum: f32 = exps.iter().sum();
+    exps.into_iter().map(|exp| exp / sum).collect()
+}
+
+/// Returns the label of the given output index, falling back to the index
+/// itself for models whose label set is shorter than their output.
+fn label(config: &ModelConfig, index: usize) -> String {
+    config
+        .labels
+        .get(index)
+        .cloned()
+        .unwrap_or_else(|| format!("class {}", index))
+}
+
+
 /// The set of 1000 ImageNet class labels.
-const LABELS: [&'static str; 1000] = [
+pub const LABELS: [&'static str; 1000] = [
     "tench",
     "goldfish",
     "great white shark",
@@ -1151,160 +1530,3 @@ const LABELS: [&'static str; 1000] = [
     "ear",
     "toilet tissue",
 ];
-//1. IMPORT IC MANAGEMENT CANISTER
-//This includes all methods and types needed
-use ic_cdk::api::management_canister::http_request::{
-    http_request, CanisterHttpRequestArgument, HttpHeader, HttpMethod, HttpResponse, TransformArgs,
-    TransformContext,
-};
-
-use ic_cdk_macros::{self, query};
-use serde_json::{self};
-
-// This struct is legacy code and is not really used in the code.
-#[derive(Serialize, Deserialize)]
-struct Context {
-    bucket_start_time_index: usize,
-    closing_price_index: usize,
-}
-
-//Update method using the HTTPS outcalls feature
-#[ic_cdk::update]
-async fn llm(prompt: String) -> String {
-    //2. SETUP ARGUMENTS FOR HTTP GET request
-
-    // 2.1 Setup the URL
-    let api_key = env!("OPENAI_API_KEY").to_string();
-    let url = "https://api.openai.com/v1/chat/completions";
-    // 2.2 prepare headers for the system http_request call
-    //Note that `HttpHeader` is declared in line 4
-    let request_headers = vec![
-        HttpHeader {
-            name: "Content-Type".to_string(),
-            value: "application/json".to_string(),
-        },
-        HttpHeader {
-            name: "Authorization".to_string(),
-            value: format!("Bearer {}", api_key),
-        },
-    ];
-
-    //note "CanisterHttpRequestArgument" and "HttpMethod" are declared in line 4.
-    //CanisterHttpRequestArgument has the following types:
-
-    // pub struct CanisterHttpRequestArgument {
-    //     pub url: String,
-    //     pub max_response_bytes: Option<u64>,
-    //     pub method: HttpMethod,
-    //     pub headers: Vec<HttpHeader>,
-    //     pub body: Option<Vec<u8>>,
-    //     pub transform: Option<TransformContext>,
-    // }
-    //see: https://docs.rs/ic-cdk/latest/ic_cdk/api/management_canister/http_request/struct.CanisterHttpRequestArgument.html
-
-    //Where "HttpMethod" has structure:
-    // pub enum HttpMethod {
-    //     GET,
-    //     POST,
-    //     HEAD,
-    // }
-    //See: https://docs.rs/ic-cdk/latest/ic_cdk/api/management_canister/http_request/enum.HttpMethod.html
-
-    //Since the body in HTTP request has type Option<Vec<u8>> it needs to look something like this: Some(vec![104, 101, 108, 108, 111]) ("hello" in ASCII)
-    //where the vector of u8s are the UTF. In order to send JSON via POST we do the following:
-    //1. Declare a JSON string to send
-    //2. Convert that JSON string to array of UTF8 (u8)
-    //3. Wrap that array in an optional
-    let json_string : String = format!("{{ \"model\": \"gpt-3.5-turbo\", \"messages\": [ {{ \"role\": \"system\", \"content\": \"I want you to act as a pet behaviorist. I will provide you with a pet and your goal is to help the owner understand why their pet has been exhibiting certain behavior, and come up with strategies for helping the pet adjust accordingly. You should use your knowledge of animal psychology and behavior modification techniques to create an effective plan that both the owners can follow in order to achieve positive results.\" }}, {{ \"role\": \"user\", \"content\": \"{}\" }} ] }}", prompt);
-
-    //note: here, r#""# is used for raw strings in Rust, which allows you to include characters like " and \ without needing to escape them.
-    //We could have used "serde_json" as well.
-    let json_utf8: Vec<u8> = json_string.into_bytes();
-    let request_body: Option<Vec<u8>> = Some(json_utf8);
-
-    // This struct is legacy code and is not really used in the code. Need to be removed in the future
-    // The "TransformContext" function does need a CONTEXT parameter, but this implementation is not necessary
-    // the TransformContext(transform, context) below accepts this "context", but it does nothing with it in this implementation.
-    // bucket_start_time_index and closing_price_index are meaninglesss
-    let context = Context {
-        bucket_start_time_index: 0,
-        closing_price_index: 4,
-    };
-
-    let request = CanisterHttpRequestArgument {
-        url: url.to_string(),
-        max_response_bytes: None, //optional for request
-        method: HttpMethod::POST,
-        headers: request_headers,
-        body: request_body,
-        transform: Some(TransformContext::new(transform, serde_json::to_vec(&context).unwrap())),
-        // transform: None, //optional for request
-    };
-
-    //3. MAKE HTTPS REQUEST AND WAIT FOR RESPONSE
-
-    //Note: in Rust, `http_request()` already sends the cycles needed
-    //so no need for explicit Cycles.add() as in Motoko
-    match http_request(request).await {
-        //4. DECODE AND RETURN THE RESPONSE
-
-        //See:https://docs.rs/ic-cdk/latest/ic_cdk/api/management_canister/http_request/struct.HttpResponse.html
-        Ok((response,)) => {
-            //if successful, `HttpResponse` has this structure:
-            // pub struct HttpResponse {
-            //     pub status: Nat,
-            //     pub headers: Vec<HttpHeader>,
-            //     pub body: Vec<u8>,
-            // }
-
-            //We need to decode that Vec<u8> that is the body into readable text.
-            //To do this, we:
-            //  1. Call `String::from_utf8()` on response.body
-            //  3. We use a switch to explicitly call out both cases of decoding the Blob into ?Text
-            let str_body = String::from_utf8(response.body)
-                .expect("Transformed response is not UTF-8 encoded.");
-            ic_cdk::api::print(format!("{:?}", str_body));
-
-            //The API response will looks like this:
-            // { successful: true }
-
-            //Return the body as a string and end the method
-            let result: String = format!(
-                "{}. See more info of the request sent at: {}/inspect",
-                str_body, url
-            );
-            result
-        }
-        Err((r, m)) => {
-            let message =
-                format!("The http_request resulted into error. RejectionCode: {r:?}, Error: {m}");
-
-            //Return the error as a string and end the method
-            message
-        }
-    }
-
-}
-
-// Strips all data that is not needed from the original response.
-#[query]
-fn transform(raw: TransformArgs) -> HttpResponse {
-
-    let headers = vec![];
-    
-
-    let mut res = HttpResponse {
-        status: raw.response.status.clone(),
-        body: raw.response.body.clone(),
-        headers,
-        ..Default::default()
-    };
-
-    if res.status == 200 {
-
-        res.body = raw.response.body;
-    } else {
-        ic_cdk::api::print(format!("Received an error from coinbase: err = {:?}", raw));
-    }
-    res
-}
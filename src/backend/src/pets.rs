@@ -0,0 +1,183 @@
+use crate::{
+    onnx,
+    principal_key::{self, Key},
+    ClassifyError, EmbedOptions, Memory, PetIdentification, PetInfo, PetMatch, MEMORY_MANAGER,
+    PETS_MEMORY_ID, PET_IDS_MEMORY_ID,
+};
+use candid::{CandidType, Deserialize, Principal};
+use ic_stable_structures::{storable::Bound, StableBTreeMap, Storable};
+use std::borrow::Cow;
+use std::cell::RefCell;
+
+const MAX_PETS: usize = 20;
+const MAX_PHOTOS: usize = 5;
+const MAX_NAME_CHARS: usize = 64;
+
+/// A photo is only identified as a pet if their embeddings are at least this
+/// similar. Different animals of the same breed often come close.
+const MIN_SIMILARITY: f32 = 0.8;
+
+/// The most similar pet is only identified if it is at least this much more
+/// similar than the runner-up.
+const MIN_MARGIN: f32 = 0.05;
+
+#[derive(CandidType, Deserialize)]
+struct StoredPet {
+    name: String,
+    /// Nanoseconds since the epoch.
+    registered_at: u64,
+    /// The embedding of every photo by the default model.
+    embeddings: Vec<Vec<f32>>,
+}
+
+impl Storable for StoredPet {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(candid::encode_one(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        candid::decode_one(&bytes).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+thread_local! {
+    static PETS: RefCell<StableBTreeMap<Key, StoredPet, Memory>> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(PETS_MEMORY_ID))),
+    );
+
+    // The id of the next pet of every owner, keyed by the bytes of the owner.
+    // Ids are never reused, so a removed pet cannot be mistaken for a newer
+    // one.
+    static NEXT_IDS: RefCell<StableBTreeMap<Vec<u8>, u64, Memory>> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(PET_IDS_MEMORY_ID))),
+    );
+}
+
+fn invalid_request(message: String) -> ClassifyError {
+    ClassifyError::InvalidRequest { message }
+}
+
+fn embed(image: &[u8]) -> Result<Vec<f32>, ClassifyError> {
+    onnx::embed(image, &EmbedOptions::default())
+}
+
+/// Registers a pet of the owner from photos of it and returns its id.
+pub fn register(
+    owner: Principal,
+    name: String,
+    photos: Vec<Vec<u8>>,
+) -> Result<u64, ClassifyError> {
+    if owner == Principal::anonymous() {
+        return Err(invalid_request(
+            "anonymous callers cannot register pets".to_string(),
+        ));
+    }
+    let name = name.trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_NAME_CHARS {
+        return Err(invalid_request(format!(
+            "name must have between 1 and {} characters",
+            MAX_NAME_CHARS
+        )));
+    }
+    if photos.is_empty() || photos.len() > MAX_PHOTOS {
+        return Err(invalid_request(format!(
+            "a pet needs between 1 and {} photos",
+            MAX_PHOTOS
+        )));
+    }
+    let ids: Vec<u64> = PETS.with_borrow(|pets| {
+        pets.range(principal_key::range(owner, None))
+            .map(|(key, _)| key.id())
+            .collect()
+    });
+    if ids.len() >= MAX_PETS {
+        return Err(ClassifyError::QuotaExceeded {
+            quota: "pets per owner".to_string(),
+            limit: MAX_PETS as u64,
+        });
+    }
+    let embeddings = photos
+        .iter()
+        .map(|photo| embed(photo))
+        .collect::<Result<_, _>>()?;
+
+    // Owners that registered pets before the counter was kept start after
+    // their newest pet.
+    let next_id = NEXT_IDS.with_borrow(|next_ids| next_ids.get(&owner.as_slice().to_vec()));
+    let id = next_id.unwrap_or_else(|| ids.last().map_or(0, |id| id + 1));
+    let pet = StoredPet {
+        name,
+        registered_at: ic_cdk::api::time(),
+        embeddings,
+    };
+    PETS.with_borrow_mut(|pets| pets.insert(Key::new(owner, id), pet));
+    NEXT_IDS.with_borrow_mut(|next_ids| next_ids.insert(owner.as_slice().to_vec(), id + 1));
+    Ok(id)
+}
+
+/// The registered pets of the owner, oldest first.
+pub fn list(owner: Principal) -> Vec<PetInfo> {
+    PETS.with_borrow(|pets| {
+        pets.range(principal_key::range(owner, None))
+            .map(|(key, pet)| PetInfo {
+                id: key.id(),
+                name: pet.name,
+                photos: pet.embeddings.len() as u32,
+                registered_at: pet.registered_at,
+            })
+            .collect()
+    })
+}
+
+/// Deletes a pet of the owner. Returns whether it existed.
+pub fn remove(owner: Principal, id: u64) -> bool {
+    PETS.with_borrow_mut(|pets| pets.remove(&Key::new(owner, id)).is_some())
+}
+
+/// Ranks the registered pets of the owner by the cosine similarity of their
+/// most similar photo to the image. The best match is identified as the pet
+/// in the image only if it is similar enough and clearly ahead of the others.
+pub fn identify(owner: Principal, image: &[u8]) -> Result<PetIdentification, ClassifyError> {
+    let embedding = embed(image)?;
+    let mut candidates: Vec<PetMatch> = PETS.with_borrow(|pets| {
+        pets.range(principal_key::range(owner, None))
+            .filter_map(|(key, pet)| {
+                let similarity = pet
+                    .embeddings
+                    .iter()
+                    .filter_map(|photo| cosine_similarity(&embedding, photo))
+                    .reduce(f32::max)?;
+                Some(PetMatch {
+                    pet_id: key.id(),
+                    name: pet.name,
+                    similarity,
+                })
+            })
+            .collect()
+    });
+    candidates.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+
+    let runner_up = candidates
+        .get(1)
+        .map_or(f32::NEG_INFINITY, |c| c.similarity);
+    let pet = candidates
+        .first()
+        .filter(|best| best.similarity >= MIN_SIMILARITY)
+        .filter(|best| best.similarity - runner_up >= MIN_MARGIN)
+        .cloned();
+    Ok(PetIdentification { candidates, pet })
+}
+
+/// Returns `None` for vectors of different lengths, which come from different
+/// models, and for zero vectors.
+fn cosine_similarity(a: &[f32], b: &[f32]) -> Option<f32> {
+    if a.len() != b.len() {
+        return None;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norms = norm(a) * norm(b);
+    (norms > 0.0).then(|| dot / norms)
+}
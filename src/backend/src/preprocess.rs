@@ -0,0 +1,126 @@
+use crate::{ClassifyError, Crop, UnsupportedFormat};
+use image::{
+    codecs::jpeg::JpegEncoder, imageops, imageops::FilterType, metadata::Orientation, DynamicImage,
+    ImageDecoder, ImageFormat, ImageReader, RgbImage,
+};
+use std::io::Cursor;
+
+/// The image formats that can be classified. Only the first frame of an
+/// animated GIF is used.
+const FORMATS: [ImageFormat; 5] = [
+    ImageFormat::Png,
+    ImageFormat::Jpeg,
+    ImageFormat::WebP,
+    ImageFormat::Gif,
+    ImageFormat::Bmp,
+];
+
+/// Images with more pixels than this are rejected before they are decoded to
+/// stay within the instruction limit. Phone cameras produce about 12 million.
+const MAX_PIXELS: u64 = 4096 * 4096;
+
+/// Images whose shorter side is below this carry too little detail to be
+/// classified.
+const MIN_SIDE: u32 = 32;
+
+/// Thumbnails are small enough to keep thousands of them in stable memory.
+const THUMBNAIL_SIZE: u32 = 64;
+const THUMBNAIL_QUALITY: u8 = 75;
+
+/// The reference preprocessing of MobileNet resizes the shorter side of the
+/// image to 256 pixels and then crops the 224 pixels in the center.
+const CROP_RATIO: f32 = 224.0 / 256.0;
+
+/// Returns the MIME types of the image formats that can be classified.
+fn accepted_formats() -> Vec<String> {
+    FORMATS
+        .iter()
+        .map(|format| format.to_mime_type().to_string())
+        .collect()
+}
+
+/// Detects the format of the image from its first bytes.
+fn sniff(bytes: &[u8]) -> Result<ImageFormat, UnsupportedFormat> {
+    match image::guess_format(bytes) {
+        Ok(format) if FORMATS.contains(&format) => Ok(format),
+        Ok(format) => Err(UnsupportedFormat {
+            format: Some(format.to_mime_type().to_string()),
+            accepted: accepted_formats(),
+        }),
+        Err(_) => Err(UnsupportedFormat {
+            format: None,
+            accepted: accepted_formats(),
+        }),
+    }
+}
+
+/// Decodes the image, turns it upright according to its EXIF orientation and
+/// scales it to a square of `size` pixels per side.
+pub fn prepare(bytes: &[u8], size: u32, crop: Crop) -> Result<RgbImage, ClassifyError> {
+    let format = sniff(bytes).map_err(ClassifyError::UnsupportedFormat)?;
+    let mut decoder = ImageReader::with_format(Cursor::new(bytes), format)
+        .into_decoder()
+        .map_err(decode_failed)?;
+    let (width, height) = decoder.dimensions();
+    if width as u64 * height as u64 > MAX_PIXELS {
+        return Err(ClassifyError::ImageTooLarge {
+            width,
+            height,
+            max_pixels: MAX_PIXELS,
+        });
+    }
+    if width.min(height) < MIN_SIDE {
+        return Err(ClassifyError::ImageTooSmall {
+            width,
+            height,
+            min_side: MIN_SIDE,
+        });
+    }
+
+    // Only JPEG and WebP carry an orientation, the other formats are taken as
+    // they are.
+    let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+    let mut image = DynamicImage::from_decoder(decoder).map_err(decode_failed)?;
+    image.apply_orientation(orientation);
+    let image = image.to_rgb8();
+    Ok(match crop {
+        Crop::Center => center_crop(&image, size),
+        Crop::Stretch => imageops::resize(&image, size, size, FilterType::Triangle),
+    })
+}
+
+/// Encodes an upright, center-cropped JPEG thumbnail of the image, or returns
+/// `None` if the image cannot be decoded.
+pub fn thumbnail(bytes: &[u8]) -> Option<Vec<u8>> {
+    let image = prepare(bytes, THUMBNAIL_SIZE, Crop::Center).ok()?;
+    let mut jpeg = vec![];
+    JpegEncoder::new_with_quality(&mut jpeg, THUMBNAIL_QUALITY)
+        .encode_image(&image)
+        .ok()?;
+    Some(jpeg)
+}
+
+fn decode_failed(err: image::ImageError) -> ClassifyError {
+    ClassifyError::DecodeFailed {
+        message: err.to_string(),
+    }
+}
+
+/// Resizes the shorter side of the image so that a square of `size` pixels
+/// covers `CROP_RATIO` of it and cuts that square out of the center.
+fn center_crop(image: &RgbImage, size: u32) -> RgbImage {
+    let (width, height) = image.dimensions();
+    let shorter = (size as f32 / CROP_RATIO).round();
+    let scale = shorter / width.min(height) as f32;
+    let width = ((width as f32 * scale).round() as u32).max(size);
+    let height = ((height as f32 * scale).round() as u32).max(size);
+    let resized = imageops::resize(image, width, height, FilterType::Triangle);
+    imageops::crop_imm(
+        &resized,
+        (width - size) / 2,
+        (height - size) / 2,
+        size,
+        size,
+    )
+    .to_image()
+}
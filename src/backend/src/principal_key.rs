@@ -0,0 +1,64 @@
+use candid::Principal;
+use ic_stable_structures::{storable::Bound, Storable};
+use std::borrow::Cow;
+use std::ops::Bound as RangeBound;
+
+/// Principals are at most 29 bytes long.
+const MAX_PRINCIPAL_LEN: usize = 29;
+
+/// Identifies an entry of a principal in a `StableBTreeMap`, such as a
+/// history entry or a pet. Entries are ordered by principal and then by id, so
+/// the entries of a principal are adjacent.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    owner: Vec<u8>,
+    id: u64,
+}
+
+impl Key {
+    pub fn new(owner: Principal, id: u64) -> Self {
+        Self {
+            owner: owner.as_slice().to_vec(),
+            id,
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+impl Storable for Key {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        let mut bytes = Vec::with_capacity(1 + MAX_PRINCIPAL_LEN + 8);
+        bytes.push(self.owner.len() as u8);
+        bytes.extend_from_slice(&self.owner);
+        bytes.resize(1 + MAX_PRINCIPAL_LEN, 0);
+        bytes.extend_from_slice(&self.id.to_be_bytes());
+        Cow::Owned(bytes)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        let len = bytes[0] as usize;
+        let id = 1 + MAX_PRINCIPAL_LEN;
+        Self {
+            owner: bytes[1..1 + len].to_vec(),
+            id: u64::from_be_bytes(bytes[id..id + 8].try_into().unwrap()),
+        }
+    }
+
+    const BOUND: Bound = Bound::Bounded {
+        max_size: 1 + MAX_PRINCIPAL_LEN as u32 + 8,
+        is_fixed_size: true,
+    };
+}
+
+/// The entries of the owner in the order of their ids, after the entry with
+/// id `after`.
+pub fn range(owner: Principal, after: Option<u64>) -> (RangeBound<Key>, RangeBound<Key>) {
+    let start = match after {
+        Some(id) => RangeBound::Excluded(Key::new(owner, id)),
+        None => RangeBound::Included(Key::new(owner, 0)),
+    };
+    (start, RangeBound::Included(Key::new(owner, u64::MAX)))
+}
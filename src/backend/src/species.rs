@@ -0,0 +1,226 @@
+use crate::{
+    cache::{Cache, Cached},
+    knowledge_base, llm, names, LlmError, LlmErrorKind, LlmResult, ProfileSource, SpeciesProfile,
+    SpeciesProfileResult, MEMORY_MANAGER, SPECIES_PROFILES_MEMORY_ID,
+    SPECIES_PROFILE_AGES_MEMORY_ID,
+};
+use candid::CandidType;
+use ic_stable_structures::{storable::Bound, Storable};
+use serde::Deserialize;
+use serde_json::json;
+use std::borrow::Cow;
+use std::cell::RefCell;
+
+const SPECIES_PROMPT: &str = "You are a zoologist writing short fact sheets for pet owners and wildlife enthusiasts. Answer with facts only and keep every field to one or two sentences. For domestic animals and breeds, leave conservation_status null.";
+
+/// Longer fields are rejected, they mean the model ignored the instructions.
+const MAX_FIELD_CHARS: usize = 1000;
+
+const MAX_CARE_TIPS: usize = 10;
+
+/// Bump when `SPECIES_PROMPT` or the schema changes so that the profiles
+/// cached for the previous prompt are no longer served.
+const PROMPT_VERSION: u32 = 1;
+
+/// Profiles written by the LLM are asked for again after 30 days.
+const TTL_NANOS: u64 = 30 * 24 * 60 * 60 * 1_000_000_000;
+
+/// The cache keeps at most this many profiles. When it is full the oldest one
+/// makes room for a new one.
+const MAX_PROFILES: u64 = 1000;
+
+#[derive(CandidType, Deserialize)]
+struct CachedProfile {
+    profile: SpeciesProfile,
+    /// The time of the outcall in nanoseconds since the epoch.
+    created_at: u64,
+}
+
+impl Storable for CachedProfile {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(candid::encode_one(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        candid::decode_one(&bytes).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+impl Cached for CachedProfile {
+    fn created_at(&self) -> u64 {
+        self.created_at
+    }
+}
+
+thread_local! {
+    static PROFILES: RefCell<Cache<CachedProfile>> = RefCell::new(MEMORY_MANAGER.with(|m| {
+        let m = m.borrow();
+        Cache::init(
+            m.get(SPECIES_PROFILES_MEMORY_ID),
+            m.get(SPECIES_PROFILE_AGES_MEMORY_ID),
+            TTL_NANOS,
+            MAX_PROFILES,
+        )
+    }));
+}
+
+fn key(label: &str) -> String {
+    format!("{}/{}", PROMPT_VERSION, label)
+}
+
+/// A fact sheet before validation, as answered by the LLM or stored in the
+/// knowledge base.
+#[derive(Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct Sheet {
+    summary: String,
+    habitat: String,
+    diet: String,
+    lifespan: String,
+    temperament: String,
+    care_tips: Vec<String>,
+    conservation_status: Option<String>,
+}
+
+/// The schema of `Sheet`. Strict structured outputs require every property to
+/// be listed as required and no additional properties.
+fn schema() -> llm::Schema {
+    llm::Schema {
+        name: "species_profile",
+        schema: json!({
+            "type": "object",
+            "properties": {
+                "summary": { "type": "string" },
+                "habitat": { "type": "string" },
+                "diet": { "type": "string" },
+                "lifespan": { "type": "string" },
+                "temperament": { "type": "string" },
+                "care_tips": { "type": "array", "items": { "type": "string" } },
+                "conservation_status": {
+                    "type": ["string", "null"],
+                    "description": "The IUCN Red List category, e.g. Least Concern."
+                }
+            },
+            "required": [
+                "summary",
+                "habitat",
+                "diet",
+                "lifespan",
+                "temperament",
+                "care_tips",
+                "conservation_status"
+            ],
+            "additionalProperties": false
+        }),
+    }
+}
+
+/// Returns the cached fact sheet about the animal or asks the LLM for one,
+/// validates it and caches it. If the LLM is not configured or fails, the
+/// fact sheet comes from the knowledge base, if it covers the animal. Labels
+/// that are neither an ImageNet label nor the common name of one are rejected
+/// before the outcall.
+pub async fn profile(label: String) -> SpeciesProfileResult {
+    let Some(label) = names::canonical_label(&label) else {
+        return SpeciesProfileResult::Err(LlmError {
+            kind: LlmErrorKind::InvalidRequest,
+            status: None,
+            message: format!("unknown label {:?}", label),
+        });
+    };
+    let now = ic_cdk::api::time();
+    if let Some(cached) = PROFILES.with_borrow(|profiles| profiles.get(&key(label), now)) {
+        return SpeciesProfileResult::Ok(cached.profile);
+    }
+    let prompt = format!("Write the fact sheet of the animal \"{}\".", label);
+    let err = match llm::complete(SPECIES_PROMPT, prompt, Some(&schema())).await {
+        LlmResult::Ok(response) => match parse(label.to_string(), &response.text) {
+            Ok(profile) => {
+                let now = ic_cdk::api::time();
+                let cached = CachedProfile {
+                    profile: profile.clone(),
+                    created_at: now,
+                };
+                PROFILES.with_borrow_mut(|profiles| profiles.insert(key(label), cached, now));
+                return SpeciesProfileResult::Ok(profile);
+            }
+            Err(message) => LlmError {
+                kind: LlmErrorKind::InvalidResponse,
+                status: None,
+                message,
+            },
+        },
+        LlmResult::Err(err) => err,
+    };
+    match knowledge_base::profile(label) {
+        Some(profile) => SpeciesProfileResult::Ok(profile),
+        None => SpeciesProfileResult::Err(err),
+    }
+}
+
+/// Deletes the cached profile of the label, or all cached profiles if no
+/// label is given. Returns the number deleted.
+pub fn invalidate(label: Option<&str>) -> u64 {
+    let label = label.map(|label| names::canonical_label(label).unwrap_or(label));
+    PROFILES.with_borrow_mut(|profiles| {
+        profiles.remove_where(|cached| label.map_or(true, |label| cached.profile.label == label))
+    })
+}
+
+fn parse(label: String, text: &str) -> Result<SpeciesProfile, String> {
+    let sheet: Sheet = serde_json::from_str(text)
+        .map_err(|err| format!("response does not match the schema: {}", err))?;
+    sheet.into_profile(label, ProfileSource::Llm)
+}
+
+impl Sheet {
+    /// Validates the fact sheet and turns it into the profile of the label.
+    pub fn into_profile(
+        self,
+        label: String,
+        source: ProfileSource,
+    ) -> Result<SpeciesProfile, String> {
+        if self.care_tips.is_empty() || self.care_tips.len() > MAX_CARE_TIPS {
+            return Err(format!(
+                "care_tips must have between 1 and {} entries",
+                MAX_CARE_TIPS
+            ));
+        }
+        Ok(SpeciesProfile {
+            label,
+            summary: field("summary", self.summary)?,
+            habitat: field("habitat", self.habitat)?,
+            diet: field("diet", self.diet)?,
+            lifespan: field("lifespan", self.lifespan)?,
+            temperament: field("temperament", self.temperament)?,
+            care_tips: self
+                .care_tips
+                .into_iter()
+                .map(|tip| field("care_tips", tip))
+                .collect::<Result<_, _>>()?,
+            conservation_status: self
+                .conservation_status
+                .filter(|status| !status.trim().is_empty())
+                .map(|status| field("conservation_status", status))
+                .transpose()?,
+            source,
+        })
+    }
+}
+
+/// Trims the field and checks that it is neither empty nor too long.
+fn field(name: &str, value: String) -> Result<String, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(format!("{} must not be empty", name));
+    }
+    if value.chars().count() > MAX_FIELD_CHARS {
+        return Err(format!(
+            "{} must not be longer than {} characters",
+            name, MAX_FIELD_CHARS
+        ));
+    }
+    Ok(value.to_string())
+}
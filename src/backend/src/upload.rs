@@ -0,0 +1,169 @@
+use crate::{Memory, ModelConfig, MEMORY_MANAGER, MODELS_MEMORY_ID, MODEL_MEMORY_ID};
+use ic_stable_structures::{storable::Bound, Memory as _, StableBTreeMap, Storable};
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use std::cell::RefCell;
+
+const WASM_PAGE_SIZE: u64 = 65536;
+
+/// An uploaded model together with the configuration it was registered with.
+pub struct StoredModel {
+    pub config: ModelConfig,
+    pub bytes: Vec<u8>,
+}
+
+/// Stored as the length of the Candid encoded config (4 bytes, little-endian),
+/// the encoded config and the raw ONNX bytes.
+impl Storable for StoredModel {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        let config = candid::encode_one(&self.config).unwrap();
+        let mut bytes = Vec::with_capacity(4 + config.len() + self.bytes.len());
+        bytes.extend_from_slice(&(config.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&config);
+        bytes.extend_from_slice(&self.bytes);
+        Cow::Owned(bytes)
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        let len = u32::from_le_bytes(bytes[0..4].try_into().unwrap()) as usize;
+        Self {
+            config: candid::decode_one(&bytes[4..4 + len]).unwrap(),
+            bytes: bytes[4 + len..].to_vec(),
+        }
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+/// An upload that has been started but not committed yet.
+struct Upload {
+    model_id: String,
+    config: ModelConfig,
+    total_len: u64,
+    sha256: [u8; 32],
+    written: u64,
+    hasher: Sha256,
+}
+
+thread_local! {
+    static UPLOAD: RefCell<Option<Upload>> = RefCell::new(None);
+
+    static MODELS: RefCell<StableBTreeMap<String, StoredModel, Memory>> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(MODELS_MEMORY_ID))),
+    );
+}
+
+/// The staging area that chunks are written to until the upload is committed.
+fn staging_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(MODEL_MEMORY_ID))
+}
+
+/// Makes sure that the memory can hold `len` bytes starting at offset zero.
+fn ensure_capacity(memory: &Memory, len: u64) -> Result<(), String> {
+    let required = (len + WASM_PAGE_SIZE - 1) / WASM_PAGE_SIZE;
+    let current = memory.size();
+    if required > current && memory.grow(required - current) < 0 {
+        return Err("failed to grow stable memory for the model".to_string());
+    }
+    Ok(())
+}
+
+/// Starts a new upload of `total_len` bytes whose SHA-256 must match `sha256`.
+/// An upload that is still in progress is discarded.
+pub fn begin(
+    model_id: String,
+    config: ModelConfig,
+    total_len: u64,
+    sha256: Vec<u8>,
+) -> Result<(), String> {
+    let sha256: [u8; 32] = sha256
+        .try_into()
+        .map_err(|_| "sha256 must be exactly 32 bytes".to_string())?;
+    if model_id.is_empty() {
+        return Err("model id must not be empty".to_string());
+    }
+    if total_len == 0 {
+        return Err("model must not be empty".to_string());
+    }
+    config.validate()?;
+    ensure_capacity(&staging_memory(), total_len)?;
+    UPLOAD.with_borrow_mut(|upload| {
+        *upload = Some(Upload {
+            model_id,
+            config,
+            total_len,
+            sha256,
+            written: 0,
+            hasher: Sha256::new(),
+        });
+    });
+    Ok(())
+}
+
+/// Appends the next chunk of the model to the upload in progress.
+pub fn append(chunk: Vec<u8>) -> Result<(), String> {
+    UPLOAD.with_borrow_mut(|upload| {
+        let upload = upload
+            .as_mut()
+            .ok_or_else(|| "no model upload in progress".to_string())?;
+        let len = chunk.len() as u64;
+        if upload.written + len > upload.total_len {
+            return Err(format!(
+                "chunk exceeds the declared model size of {} bytes",
+                upload.total_len
+            ));
+        }
+        staging_memory().write(upload.written, &chunk);
+        upload.hasher.update(&chunk);
+        upload.written += len;
+        Ok(())
+    })
+}
+
+/// Verifies the uploaded bytes and hands them to `load`. The upload is stored
+/// under its model id only if `load` succeeds, so a model that fails to build
+/// is never picked up again after an upgrade.
+pub fn commit(
+    load: impl FnOnce(&str, &[u8], &ModelConfig) -> Result<(), String>,
+) -> Result<(), String> {
+    let upload = UPLOAD
+        .with_borrow_mut(|upload| upload.take())
+        .ok_or_else(|| "no model upload in progress".to_string())?;
+    if upload.written != upload.total_len {
+        return Err(format!(
+            "received {} of {} bytes",
+            upload.written, upload.total_len
+        ));
+    }
+    let digest = upload.hasher.finalize();
+    if digest.as_slice() != upload.sha256 {
+        return Err("sha256 of the uploaded model does not match".to_string());
+    }
+
+    let mut bytes = vec![0; upload.total_len as usize];
+    staging_memory().read(0, &mut bytes);
+    load(&upload.model_id, &bytes, &upload.config)?;
+
+    MODELS.with_borrow_mut(|models| {
+        models.insert(
+            upload.model_id,
+            StoredModel {
+                config: upload.config,
+                bytes,
+            },
+        );
+    });
+    Ok(())
+}
+
+/// Returns all committed models keyed by their id.
+pub fn stored_models() -> Vec<(String, StoredModel)> {
+    MODELS.with_borrow(|models| models.iter().collect())
+}
+
+/// Deletes the committed model with the given id, if there is one.
+pub fn remove(model_id: &str) {
+    MODELS.with_borrow_mut(|models| {
+        models.remove(&model_id.to_string());
+    });
+}
@@ -0,0 +1,453 @@
+use crate::{
+    onnx,
+    principal_key::{self, Key},
+    ClassifyError, EmbedOptions, Memory, SimilarImage, SimilarityIndexInfo,
+    INDEX_CENTROIDS_MEMORY_ID, INDEX_ENTRIES_MEMORY_ID, INDEX_LOCATIONS_MEMORY_ID,
+    INDEX_OWNERS_MEMORY_ID, INDEX_REBUILD_MEMORY_ID, MEMORY_MANAGER,
+};
+use candid::{CandidType, Deserialize, Principal};
+use ic_stable_structures::{storable::Bound, StableBTreeMap, StableCell, Storable};
+use std::borrow::Cow;
+use std::cell::RefCell;
+
+/// The index keeps at most this many images, so that searches stay cheap
+/// while it is rebuilt and every image is compared.
+const MAX_ENTRIES: u64 = 20_000;
+
+/// Every principal can index at most this many images.
+const MAX_ENTRIES_PER_OWNER: usize = 1000;
+
+/// Until the index holds this many images, searches compare the image with
+/// every indexed image. Then the images are clustered into `LISTS` lists and
+/// searches only compare it with the images in the `PROBES` nearest lists.
+const TRAINING_THRESHOLD: u64 = 512;
+const LISTS: usize = 32;
+const PROBES: usize = 4;
+
+/// The lists are trained on at most this many images, which bounds the work
+/// of the message that starts a rebuild.
+const MAX_TRAINING_SAMPLES: usize = 2048;
+const TRAINING_ITERATIONS: usize = 10;
+
+/// A rebuild moves this many images to their new lists per message.
+const REBUILD_BATCH: usize = 1000;
+
+const DEFAULT_K: u32 = 10;
+const MAX_K: u32 = 100;
+
+/// An embedding quantised to one byte per dimension. Dividing the bytes by
+/// `scale` restores the embedding, which has unit length.
+#[derive(CandidType, Deserialize)]
+struct Entry {
+    owner: Principal,
+    /// Nanoseconds since the epoch.
+    indexed_at: u64,
+    scale: f32,
+    vector: Vec<i8>,
+}
+
+impl Storable for Entry {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(candid::encode_one(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        candid::decode_one(&bytes).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+/// The center of every list, with unit length. Empty until the index is
+/// trained, when every entry is in list 0.
+#[derive(CandidType, Deserialize, Default)]
+struct Centroids(Vec<Vec<f32>>);
+
+impl Storable for Centroids {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(candid::encode_one(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        candid::decode_one(&bytes).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+/// A rebuild in progress: the new center of every list and the id of the
+/// last image moved to its new list. No rebuild is in progress while there
+/// are no centers.
+#[derive(CandidType, Deserialize, Default)]
+struct Rebuild {
+    centroids: Vec<Vec<f32>>,
+    moved: Option<u64>,
+}
+
+impl Storable for Rebuild {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(candid::encode_one(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        candid::decode_one(&bytes).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+thread_local! {
+    // Entries keyed by their list and id, so that a list can be scanned.
+    static ENTRIES: RefCell<StableBTreeMap<(u32, u64), Entry, Memory>> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(INDEX_ENTRIES_MEMORY_ID))),
+    );
+
+    // The list of every entry, keyed by its id.
+    static LOCATIONS: RefCell<StableBTreeMap<u64, u32, Memory>> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(INDEX_LOCATIONS_MEMORY_ID))),
+    );
+
+    static CENTROIDS: RefCell<StableCell<Centroids, Memory>> = RefCell::new(
+        StableCell::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(INDEX_CENTROIDS_MEMORY_ID)),
+            Centroids::default(),
+        )
+        .unwrap(),
+    );
+
+    // The ids of the images of every owner, to enforce the limit per owner.
+    static OWNERS: RefCell<StableBTreeMap<Key, (), Memory>> = RefCell::new(
+        StableBTreeMap::init(MEMORY_MANAGER.with(|m| m.borrow().get(INDEX_OWNERS_MEMORY_ID))),
+    );
+
+    static REBUILD: RefCell<StableCell<Rebuild, Memory>> = RefCell::new(
+        StableCell::init(
+            MEMORY_MANAGER.with(|m| m.borrow().get(INDEX_REBUILD_MEMORY_ID)),
+            Rebuild::default(),
+        )
+        .unwrap(),
+    );
+}
+
+/// Indexes the owners of the images indexed before the owners were kept.
+pub fn setup() {
+    if OWNERS.with_borrow(|owners| !owners.is_empty()) {
+        return;
+    }
+    ENTRIES.with_borrow(|entries| {
+        OWNERS.with_borrow_mut(|owners| {
+            for ((_, id), entry) in entries.iter() {
+                owners.insert(Key::new(entry.owner, id), ());
+            }
+        })
+    });
+}
+
+fn invalid_request(message: String) -> ClassifyError {
+    ClassifyError::InvalidRequest { message }
+}
+
+fn embed(image: &[u8]) -> Result<Vec<f32>, ClassifyError> {
+    onnx::embed(image, &EmbedOptions::default())
+}
+
+fn quantise(embedding: &[f32]) -> (f32, Vec<i8>) {
+    let max = embedding.iter().fold(0.0f32, |max, x| max.max(x.abs()));
+    let scale = if max > 0.0 { 127.0 / max } else { 1.0 };
+    let vector = embedding
+        .iter()
+        .map(|x| (x * scale).round() as i8)
+        .collect();
+    (scale, vector)
+}
+
+fn dequantise(entry: &Entry) -> Vec<f32> {
+    entry
+        .vector
+        .iter()
+        .map(|&x| x as f32 / entry.scale)
+        .collect()
+}
+
+/// The cosine similarity of an embedding to an entry. `None` if the entry
+/// was indexed with a model of a different size.
+fn similarity(embedding: &[f32], entry: &Entry) -> Option<f32> {
+    (embedding.len() == entry.vector.len()).then(|| {
+        let dot: f32 = embedding
+            .iter()
+            .zip(&entry.vector)
+            .map(|(x, &y)| x * y as f32)
+            .sum();
+        // Rounding can push the similarity of near duplicates above one.
+        (dot / entry.scale).clamp(-1.0, 1.0)
+    })
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Returns the lists whose centers are most similar to the embedding, most
+/// similar first.
+fn nearest_lists(centroids: &[Vec<f32>], embedding: &[f32], count: usize) -> Vec<u32> {
+    if centroids.is_empty() {
+        return vec![0];
+    }
+    let mut lists: Vec<(u32, f32)> = centroids
+        .iter()
+        .enumerate()
+        .map(|(list, centroid)| (list as u32, dot(centroid, embedding)))
+        .collect();
+    lists.sort_by(|a, b| b.1.total_cmp(&a.1));
+    lists
+        .into_iter()
+        .take(count)
+        .map(|(list, _)| list)
+        .collect()
+}
+
+/// Adds the image to the index under the owner and returns its id.
+pub fn insert(owner: Principal, image: &[u8]) -> Result<u64, ClassifyError> {
+    if owner == Principal::anonymous() {
+        return Err(invalid_request(
+            "anonymous callers cannot index images".to_string(),
+        ));
+    }
+    if LOCATIONS.with_borrow(|locations| locations.len()) >= MAX_ENTRIES {
+        return Err(ClassifyError::QuotaExceeded {
+            quota: "indexed images".to_string(),
+            limit: MAX_ENTRIES,
+        });
+    }
+    let owned = OWNERS.with_borrow(|owners| {
+        owners
+            .range(principal_key::range(owner, None))
+            .take(MAX_ENTRIES_PER_OWNER)
+            .count()
+    });
+    if owned >= MAX_ENTRIES_PER_OWNER {
+        return Err(ClassifyError::QuotaExceeded {
+            quota: "indexed images per owner".to_string(),
+            limit: MAX_ENTRIES_PER_OWNER as u64,
+        });
+    }
+    let embedding = embed(image)?;
+    // During a rebuild new images already go to the new lists.
+    let centroids = if rebuilding() {
+        REBUILD.with_borrow(|rebuild| rebuild.get().centroids.clone())
+    } else {
+        CENTROIDS.with_borrow(|centroids| centroids.get().0.clone())
+    };
+    let list = nearest_lists(&centroids, &embedding, 1)[0];
+    let (scale, vector) = quantise(&embedding);
+    let indexed_at = ic_cdk::api::time();
+
+    let id = LOCATIONS.with_borrow_mut(|locations| {
+        let mut id = indexed_at;
+        while locations.contains_key(&id) {
+            id += 1;
+        }
+        locations.insert(id, list);
+        id
+    });
+    ENTRIES.with_borrow_mut(|entries| {
+        entries.insert(
+            (list, id),
+            Entry {
+                owner,
+                indexed_at,
+                scale,
+                vector,
+            },
+        );
+    });
+    OWNERS.with_borrow_mut(|owners| owners.insert(Key::new(owner, id), ()));
+
+    let untrained = CENTROIDS.with_borrow(|centroids| centroids.get().0.is_empty());
+    if untrained && !rebuilding() && info().entries >= TRAINING_THRESHOLD {
+        rebuild();
+    }
+    Ok(id)
+}
+
+/// Whether the images are being moved to the lists of a rebuild.
+fn rebuilding() -> bool {
+    REBUILD.with_borrow(|rebuild| !rebuild.get().centroids.is_empty())
+}
+
+/// Returns the `k` indexed images most similar to the image, most similar
+/// first.
+pub fn search(image: &[u8], k: Option<u32>) -> Result<Vec<SimilarImage>, ClassifyError> {
+    let k = k.unwrap_or(DEFAULT_K).clamp(1, MAX_K) as usize;
+    let embedding = embed(image)?;
+    // During a rebuild the images of a list can be in the old or the new list,
+    // so every list is searched.
+    let lists = if rebuilding() {
+        (0..LISTS as u32).collect()
+    } else {
+        CENTROIDS.with_borrow(|centroids| nearest_lists(&centroids.get().0, &embedding, PROBES))
+    };
+    let mut results: Vec<SimilarImage> = ENTRIES.with_borrow(|entries| {
+        lists
+            .into_iter()
+            .flat_map(|list| entries.range((list, 0)..=(list, u64::MAX)))
+            .filter_map(|((_, id), entry)| {
+                Some(SimilarImage {
+                    id,
+                    similarity: similarity(&embedding, &entry)?,
+                    owner: entry.owner,
+                    indexed_at: entry.indexed_at,
+                })
+            })
+            .collect()
+    });
+    results.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+    results.truncate(k);
+    Ok(results)
+}
+
+/// Removes an image from the index. Only its owner and controllers may
+/// remove it.
+pub fn remove(caller: Principal, id: u64) -> Result<(), String> {
+    let list = LOCATIONS
+        .with_borrow(|locations| locations.get(&id))
+        .ok_or_else(|| format!("image {} is not indexed", id))?;
+    let owner = ENTRIES.with_borrow_mut(|entries| {
+        let owner = entries.get(&(list, id)).map(|entry| entry.owner);
+        if owner != Some(caller) && !ic_cdk::api::is_controller(&caller) {
+            return Err(format!("image {} was indexed by another principal", id));
+        }
+        entries.remove(&(list, id));
+        Ok(owner)
+    })?;
+    LOCATIONS.with_borrow_mut(|locations| locations.remove(&id));
+    if let Some(owner) = owner {
+        OWNERS.with_borrow_mut(|owners| owners.remove(&Key::new(owner, id)));
+    }
+    Ok(())
+}
+
+pub fn info() -> SimilarityIndexInfo {
+    SimilarityIndexInfo {
+        entries: LOCATIONS.with_borrow(|locations| locations.len()),
+        lists: CENTROIDS.with_borrow(|centroids| centroids.get().0.len() as u32),
+        rebuilding: rebuilding(),
+    }
+}
+
+/// Clusters a sample of the indexed images into lists with spherical k-means
+/// and starts moving every image to the list of its nearest center, which
+/// continues in later messages. Centers start at images spread evenly over
+/// the ids, so every replica computes the same lists. A rebuild in progress
+/// starts over.
+pub fn rebuild() {
+    let count = info().entries as usize;
+    if count == 0 {
+        return;
+    }
+    let step = count.div_ceil(MAX_TRAINING_SAMPLES);
+    let samples: Vec<Vec<f32>> = LOCATIONS.with_borrow(|locations| {
+        ENTRIES.with_borrow(|entries| {
+            locations
+                .iter()
+                .step_by(step)
+                .filter_map(|(id, list)| entries.get(&(list, id)))
+                .map(|entry| dequantise(&entry))
+                .collect()
+        })
+    });
+    let rebuild = Rebuild {
+        centroids: train(&samples),
+        moved: None,
+    };
+    REBUILD.with_borrow_mut(|cell| cell.set(rebuild).unwrap());
+    schedule();
+}
+
+/// Moves the next batch of images to their new lists and, once every image
+/// has moved, makes the new lists the ones searches use. Returns whether
+/// images are left to move.
+pub fn continue_rebuild() -> bool {
+    let Rebuild { centroids, moved } =
+        REBUILD.with_borrow_mut(|cell| cell.set(Rebuild::default()).unwrap());
+    if centroids.is_empty() {
+        return false;
+    }
+    let start = match moved {
+        Some(id) => std::ops::Bound::Excluded(id),
+        None => std::ops::Bound::Unbounded,
+    };
+    let batch: Vec<(u64, u32)> = LOCATIONS.with_borrow(|locations| {
+        locations
+            .range((start, std::ops::Bound::Unbounded))
+            .take(REBUILD_BATCH)
+            .collect()
+    });
+    ENTRIES.with_borrow_mut(|entries| {
+        LOCATIONS.with_borrow_mut(|locations| {
+            for &(id, list) in &batch {
+                let Some(entry) = entries.remove(&(list, id)) else {
+                    continue;
+                };
+                let nearest = nearest_lists(&centroids, &dequantise(&entry), 1)[0];
+                entries.insert((nearest, id), entry);
+                locations.insert(id, nearest);
+            }
+        })
+    });
+    if batch.len() < REBUILD_BATCH {
+        CENTROIDS.with_borrow_mut(|cell| cell.set(Centroids(centroids)).unwrap());
+        return false;
+    }
+    let rebuild = Rebuild {
+        centroids,
+        moved: batch.last().map(|&(id, _)| id),
+    };
+    REBUILD.with_borrow_mut(|cell| cell.set(rebuild).unwrap());
+    true
+}
+
+/// Continues the rebuild in a call of the canister to itself, so that every
+/// batch gets the instruction limit of a message of its own.
+pub fn schedule() {
+    ic_cdk::spawn(async {
+        let result: ic_cdk::api::call::CallResult<()> =
+            ic_cdk::call(ic_cdk::id(), "continue_similarity_index_rebuild", ()).await;
+        if let Err((code, message)) = result {
+            ic_cdk::println!(
+                "Failed to continue the similarity index rebuild: {:?} {}",
+                code,
+                message
+            );
+        }
+    });
+}
+
+fn train(samples: &[Vec<f32>]) -> Vec<Vec<f32>> {
+    let dimensions = samples[0].len();
+    let samples: Vec<&Vec<f32>> = samples
+        .iter()
+        .filter(|sample| sample.len() == dimensions)
+        .collect();
+    let lists = LISTS.min(samples.len());
+    let mut centroids: Vec<Vec<f32>> = (0..lists)
+        .map(|list| samples[list * samples.len() / lists].clone())
+        .collect();
+    for _ in 0..TRAINING_ITERATIONS {
+        let mut sums = vec![vec![0.0f32; dimensions]; lists];
+        for sample in &samples {
+            let list = nearest_lists(&centroids, sample, 1)[0] as usize;
+            for (sum, x) in sums[list].iter_mut().zip(sample.iter()) {
+                *sum += x;
+            }
+        }
+        for (centroid, sum) in centroids.iter_mut().zip(sums) {
+            let norm = dot(&sum, &sum).sqrt();
+            // A list that lost all of its samples keeps its center.
+            if norm > 0.0 {
+                *centroid = sum.into_iter().map(|x| x / norm).collect();
+            }
+        }
+    }
+    centroids
+}
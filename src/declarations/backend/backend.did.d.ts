@@ -2,11 +2,87 @@ import type { Principal } from '@dfinity/principal';
 import type { ActorMethod } from '@dfinity/agent';
 import type { IDL } from '@dfinity/candid';
 
-export interface Classification { 'label' : string, 'score' : number }
-export interface ClassificationError { 'message' : string }
+export interface BreedMix {
+  'breeds' : Array<BreedShare>,
+  'pet_score' : number,
+  'entropy' : number,
+  'mixed_breed_likely' : boolean,
+}
+export type BreedMixResult = { 'Ok' : BreedMix } |
+  { 'Err' : ClassifyError };
+export interface BreedShare {
+  'label' : string,
+  'percentage' : number,
+  'synset' : [] | [string],
+}
+export interface Classification {
+  'scientific_name' : [] | [string],
+  'raw_logit' : number,
+  'label' : string,
+  'score' : number,
+  'synset' : [] | [string],
+}
+export interface ClassificationError {
+  'kind' : [] | [ClassifyError],
+  'message' : string,
+}
 export type ClassificationResult = { 'Ok' : Array<Classification> } |
   { 'Err' : ClassificationError };
+export type ClassificationResultV2 = { 'Ok' : Array<Classification> } |
+  { 'Err' : ClassifyError } |
+  { 'NotAnimal' : NotAnimal };
+export type ClassifyError = { 'UnsupportedFormat' : UnsupportedFormat } |
+  { 'InferenceFailed' : { 'message' : string } } |
+  { 'ImageTooLarge' : {
+    'height' : number,
+    'max_pixels' : bigint,
+    'width' : number,
+  } } |
+  { 'ImageTooSmall' : {
+    'height' : number,
+    'min_side' : number,
+    'width' : number,
+  } } |
+  { 'InvalidRequest' : { 'message' : string } } |
+  { 'ModelNotLoaded' : { 'model_id' : string } } |
+  { 'QuotaExceeded' : { 'quota' : string, 'limit' : bigint } } |
+  { 'DecodeFailed' : { 'message' : string } };
+export interface ClassifyOptions {
+  'top_k' : [] | [number],
+  'thumbnail' : [] | [boolean],
+  'animals_only' : [] | [boolean],
+  'include_labels' : [] | [Array<string>],
+  'crop' : [] | [Crop],
+  'locale' : [] | [string],
+  'min_score' : [] | [number],
+  'rollup' : [] | [Rank],
+  'model_id' : [] | [string],
+  'exclude_labels' : [] | [Array<string>],
+}
+export interface CommonName { 'name' : string, 'locale' : string }
+export type Crop = { 'Center' : null } |
+  { 'Stretch' : null };
+export interface EmbedOptions {
+  'crop' : [] | [Crop],
+  'normalization' : [] | [Normalization],
+  'model_id' : [] | [string],
+}
+export type EmbedResult = { 'Ok' : Array<number> } |
+  { 'Err' : ClassifyError };
 export type HeaderField = [string, string];
+export interface HistoryEntry {
+  'id' : bigint,
+  'thumbnail' : [] | [Uint8Array | number[]],
+  'results' : Array<Classification>,
+  'animal_score' : [] | [number],
+  'timestamp' : bigint,
+  'image_sha256' : Uint8Array | number[],
+  'model_id' : string,
+}
+export interface HistoryPage {
+  'next' : [] | [bigint],
+  'entries' : Array<HistoryEntry>,
+}
 export interface HttpRequest {
   'url' : string,
   'method' : string,
@@ -16,15 +92,264 @@ export interface HttpRequest {
 export interface HttpResponse {
   'body' : Uint8Array | number[],
   'headers' : Array<HeaderField>,
+  'upgrade' : [] | [boolean],
   'status_code' : number,
 }
+export type IdentifyPetResult = { 'Ok' : PetIdentification } |
+  { 'Err' : ClassifyError };
+export interface InboxPage {
+  'notifications' : Array<Notification>,
+  'more' : boolean,
+  'next' : [] | [bigint],
+}
+export type IndexImageResult = { 'Ok' : bigint } |
+  { 'Err' : ClassifyError };
+export interface KnowledgeBaseInfo {
+  'entries' : number,
+  'version' : number,
+  'embedded' : boolean,
+}
+export interface LabelInfo {
+  'lineage' : [] | [Lineage],
+  'scientific_name' : [] | [string],
+  'common_names' : Array<CommonName>,
+  'label' : string,
+  'index' : number,
+  'synset' : [] | [string],
+}
+export type LabelsResult = { 'Ok' : Array<LabelInfo> } |
+  { 'Err' : string };
+export interface Lineage {
+  'order' : [] | [string],
+  'class' : [] | [string],
+  'species' : string,
+  'family' : [] | [string],
+}
+export interface LlmConfig {
+  'base_url' : [] | [string],
+  'model' : string,
+  'provider' : LlmProvider,
+  'temperature' : [] | [number],
+  'max_tokens' : [] | [number],
+}
+export interface LlmError {
+  'status' : [] | [number],
+  'kind' : LlmErrorKind,
+  'message' : string,
+}
+export type LlmErrorKind = { 'OutcallFailed' : null } |
+  { 'InvalidResponse' : null } |
+  { 'ProviderError' : null } |
+  { 'InvalidRequest' : null } |
+  { 'NotConfigured' : null };
+export type LlmProvider = { 'OpenAiCompatible' : null } |
+  { 'OpenAi' : null } |
+  { 'Anthropic' : null };
+export interface LlmResponse {
+  'model' : string,
+  'text' : string,
+  'usage' : [] | [LlmUsage],
+}
+export type LlmResult = { 'Ok' : LlmResponse } |
+  { 'Err' : LlmError };
+export interface LlmUsage {
+  'completion_tokens' : number,
+  'prompt_tokens' : number,
+  'total_tokens' : number,
+}
+export interface ModelConfig {
+  'std' : Array<number>,
+  'animal_classes' : [] | [Array<number>],
+  'embedding_output' : [] | [string],
+  'temperature' : [] | [number],
+  'mean' : Array<number>,
+  'labels' : Array<string>,
+  'synsets' : [] | [Array<string>],
+  'input_size' : number,
+}
+export interface ModelInfo {
+  'id' : string,
+  'label_count' : number,
+  'embeds' : boolean,
+  'input_size' : number,
+}
+export interface NewReport {
+  'contact' : string,
+  'area' : string,
+  'kind' : ReportKind,
+  'photos' : Array<Uint8Array | number[]>,
+}
+export type Normalization = { 'L2' : null } |
+  { 'Raw' : null };
+export interface NotAnimal {
+  'animal_score' : number,
+  'top_label' : Classification,
+}
+export interface Notification {
+  'id' : bigint,
+  'report_id' : bigint,
+  'created_at' : bigint,
+  'candidate' : ReportMatch,
+}
+export type Pet = { 'Cat' : null } |
+  { 'Dog' : null };
+export interface PetIdentification {
+  'pet' : [] | [PetMatch],
+  'candidates' : Array<PetMatch>,
+}
+export interface PetInfo {
+  'id' : bigint,
+  'name' : string,
+  'registered_at' : bigint,
+  'photos' : number,
+}
+export interface PetMatch {
+  'pet_id' : bigint,
+  'name' : string,
+  'similarity' : number,
+}
+export type PostReportResult = { 'Ok' : bigint } |
+  { 'Err' : ClassifyError };
+export type ProfileSource = { 'Llm' : null } |
+  { 'KnowledgeBase' : { 'taxon' : string, 'version' : number } };
+export type Rank = { 'Label' : null } |
+  { 'Family' : null } |
+  { 'Species' : null } |
+  { 'Order' : null } |
+  { 'Class' : null };
+export type RegisterPetResult = { 'Ok' : bigint } |
+  { 'Err' : ClassifyError };
+export interface Report {
+  'id' : bigint,
+  'breeds' : Array<Classification>,
+  'contact' : string,
+  'owner' : Principal,
+  'area' : string,
+  'kind' : ReportKind,
+  'created_at' : bigint,
+  'thumbnails' : Array<Uint8Array | number[]>,
+}
+export type ReportKind = { 'Lost' : null } |
+  { 'Found' : null };
+export interface ReportMatch {
+  'report_id' : bigint,
+  'breed_agreement' : number,
+  'score' : number,
+  'similarity' : number,
+}
+export type ReportMatchesResult = { 'Ok' : Array<ReportMatch> } |
+  { 'Err' : string };
+export type Result = { 'Ok' : null } |
+  { 'Err' : string };
+export type SearchSimilarResult = { 'Ok' : Array<SimilarImage> } |
+  { 'Err' : ClassifyError };
+export interface SimilarImage {
+  'id' : bigint,
+  'owner' : Principal,
+  'similarity' : number,
+  'indexed_at' : bigint,
+}
+export interface SimilarityIndexInfo {
+  'rebuilding' : boolean,
+  'lists' : number,
+  'entries' : bigint,
+}
+export interface SpeciesProfile {
+  'care_tips' : Array<string>,
+  'source' : ProfileSource,
+  'temperament' : string,
+  'diet' : string,
+  'habitat' : string,
+  'lifespan' : string,
+  'label' : string,
+  'summary' : string,
+  'conservation_status' : [] | [string],
+}
+export type SpeciesProfileResult = { 'Ok' : SpeciesProfile } |
+  { 'Err' : LlmError };
+export interface UnsupportedFormat {
+  'accepted' : Array<string>,
+  'format' : [] | [string],
+}
 export interface _SERVICE {
+  'acknowledge_inbox' : ActorMethod<[bigint], bigint>,
+  'append_knowledge_base_chunk' : ActorMethod<[Uint8Array | number[]], Result>,
+  'append_model_chunk' : ActorMethod<[Uint8Array | number[]], Result>,
+  'begin_knowledge_base_upload' : ActorMethod<
+    [bigint, Uint8Array | number[]],
+    Result
+  >,
+  'begin_model_upload' : ActorMethod<
+    [string, ModelConfig, bigint, Uint8Array | number[]],
+    Result
+  >,
+  'breed_mix' : ActorMethod<
+    [Uint8Array | number[], Pet, ClassifyOptions],
+    BreedMixResult
+  >,
+  'cached_description' : ActorMethod<
+    [string, [] | [string]],
+    [] | [LlmResponse]
+  >,
   'classify' : ActorMethod<[Uint8Array | number[]], ClassificationResult>,
   'classify_query' : ActorMethod<[Uint8Array | number[]], ClassificationResult>,
+  'classify_query_v2' : ActorMethod<
+    [Uint8Array | number[], ClassifyOptions],
+    ClassificationResultV2
+  >,
+  'classify_v2' : ActorMethod<
+    [Uint8Array | number[], ClassifyOptions],
+    ClassificationResultV2
+  >,
+  'classify_with' : ActorMethod<
+    [string, Uint8Array | number[]],
+    ClassificationResult
+  >,
+  'clear_my_history' : ActorMethod<[], bigint>,
+  'close_report' : ActorMethod<[bigint], Result>,
+  'commit_knowledge_base_upload' : ActorMethod<[], Result>,
+  'commit_model_upload' : ActorMethod<[], Result>,
+  'continue_similarity_index_rebuild' : ActorMethod<[], undefined>,
+  'delete_history_entry' : ActorMethod<[bigint], Result>,
+  'describe' : ActorMethod<[string, [] | [string]], LlmResult>,
+  'embed' : ActorMethod<[Uint8Array | number[], EmbedOptions], EmbedResult>,
+  'get_llm_config' : ActorMethod<[], LlmConfig>,
+  'get_report' : ActorMethod<[bigint], [] | [Report]>,
+  'has_llm_credentials' : ActorMethod<[], boolean>,
   'http_request' : ActorMethod<[HttpRequest], HttpResponse>,
-  'llm' : ActorMethod<[string], string>,
+  'http_request_update' : ActorMethod<[HttpRequest], HttpResponse>,
+  'identify_my_pet' : ActorMethod<[Uint8Array | number[]], IdentifyPetResult>,
+  'index_image' : ActorMethod<[Uint8Array | number[]], IndexImageResult>,
+  'invalidate_descriptions' : ActorMethod<[[] | [string]], bigint>,
+  'invalidate_species_profiles' : ActorMethod<[[] | [string]], bigint>,
+  'knowledge_base_info' : ActorMethod<[], KnowledgeBaseInfo>,
+  'knowledge_base_profile' : ActorMethod<[string], [] | [SpeciesProfile]>,
+  'list_labels' : ActorMethod<[[] | [string]], LabelsResult>,
+  'list_models' : ActorMethod<[], Array<ModelInfo>>,
+  'llm' : ActorMethod<[string], LlmResult>,
+  'my_history' : ActorMethod<[[] | [bigint], [] | [number]], HistoryPage>,
+  'my_inbox' : ActorMethod<[[] | [bigint], [] | [number]], InboxPage>,
+  'my_pets' : ActorMethod<[], Array<PetInfo>>,
+  'my_reports' : ActorMethod<[], Array<Report>>,
+  'post_report' : ActorMethod<[NewReport], PostReportResult>,
+  'rebuild_similarity_index' : ActorMethod<[], SimilarityIndexInfo>,
+  'register_pet' : ActorMethod<
+    [string, Array<Uint8Array | number[]>],
+    RegisterPetResult
+  >,
+  'remove_from_index' : ActorMethod<[bigint], Result>,
+  'remove_model' : ActorMethod<[string], Result>,
+  'remove_pet' : ActorMethod<[bigint], Result>,
+  'report_matches' : ActorMethod<[bigint, [] | [number]], ReportMatchesResult>,
   'run' : ActorMethod<[], ClassificationResult>,
-  'send_http_post_request' : ActorMethod<[string], string>,
+  'search_similar' : ActorMethod<
+    [Uint8Array | number[], [] | [number]],
+    SearchSimilarResult
+  >,
+  'set_llm_config' : ActorMethod<[LlmConfig], Result>,
+  'set_llm_credentials' : ActorMethod<[string, [] | [LlmProvider]], Result>,
+  'similarity_index_info' : ActorMethod<[], SimilarityIndexInfo>,
+  'species_profile' : ActorMethod<[string], SpeciesProfileResult>,
 }
 export declare const idlFactory: IDL.InterfaceFactory;
 export declare const init: (args: { IDL: typeof IDL }) => IDL.Type[];
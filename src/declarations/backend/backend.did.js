@@ -1,13 +1,153 @@
 export const idlFactory = ({ IDL }) => {
+  const Result = IDL.Variant({ 'Ok' : IDL.Null, 'Err' : IDL.Text });
+  const ModelConfig = IDL.Record({
+    'std' : IDL.Vec(IDL.Float32),
+    'animal_classes' : IDL.Opt(IDL.Vec(IDL.Nat32)),
+    'embedding_output' : IDL.Opt(IDL.Text),
+    'temperature' : IDL.Opt(IDL.Float32),
+    'mean' : IDL.Vec(IDL.Float32),
+    'labels' : IDL.Vec(IDL.Text),
+    'synsets' : IDL.Opt(IDL.Vec(IDL.Text)),
+    'input_size' : IDL.Nat32,
+  });
+  const Pet = IDL.Variant({ 'Cat' : IDL.Null, 'Dog' : IDL.Null });
+  const Crop = IDL.Variant({ 'Center' : IDL.Null, 'Stretch' : IDL.Null });
+  const Rank = IDL.Variant({
+    'Label' : IDL.Null,
+    'Family' : IDL.Null,
+    'Species' : IDL.Null,
+    'Order' : IDL.Null,
+    'Class' : IDL.Null,
+  });
+  const ClassifyOptions = IDL.Record({
+    'top_k' : IDL.Opt(IDL.Nat32),
+    'thumbnail' : IDL.Opt(IDL.Bool),
+    'animals_only' : IDL.Opt(IDL.Bool),
+    'include_labels' : IDL.Opt(IDL.Vec(IDL.Text)),
+    'crop' : IDL.Opt(Crop),
+    'locale' : IDL.Opt(IDL.Text),
+    'min_score' : IDL.Opt(IDL.Float32),
+    'rollup' : IDL.Opt(Rank),
+    'model_id' : IDL.Opt(IDL.Text),
+    'exclude_labels' : IDL.Opt(IDL.Vec(IDL.Text)),
+  });
+  const BreedShare = IDL.Record({
+    'label' : IDL.Text,
+    'percentage' : IDL.Float32,
+    'synset' : IDL.Opt(IDL.Text),
+  });
+  const BreedMix = IDL.Record({
+    'breeds' : IDL.Vec(BreedShare),
+    'pet_score' : IDL.Float32,
+    'entropy' : IDL.Float32,
+    'mixed_breed_likely' : IDL.Bool,
+  });
+  const UnsupportedFormat = IDL.Record({
+    'accepted' : IDL.Vec(IDL.Text),
+    'format' : IDL.Opt(IDL.Text),
+  });
+  const ClassifyError = IDL.Variant({
+    'UnsupportedFormat' : UnsupportedFormat,
+    'InferenceFailed' : IDL.Record({ 'message' : IDL.Text }),
+    'ImageTooLarge' : IDL.Record({
+      'height' : IDL.Nat32,
+      'max_pixels' : IDL.Nat64,
+      'width' : IDL.Nat32,
+    }),
+    'ImageTooSmall' : IDL.Record({
+      'height' : IDL.Nat32,
+      'min_side' : IDL.Nat32,
+      'width' : IDL.Nat32,
+    }),
+    'InvalidRequest' : IDL.Record({ 'message' : IDL.Text }),
+    'ModelNotLoaded' : IDL.Record({ 'model_id' : IDL.Text }),
+    'QuotaExceeded' : IDL.Record({ 'quota' : IDL.Text, 'limit' : IDL.Nat64 }),
+    'DecodeFailed' : IDL.Record({ 'message' : IDL.Text }),
+  });
+  const BreedMixResult = IDL.Variant({
+    'Ok' : BreedMix,
+    'Err' : ClassifyError,
+  });
+  const LlmUsage = IDL.Record({
+    'completion_tokens' : IDL.Nat32,
+    'prompt_tokens' : IDL.Nat32,
+    'total_tokens' : IDL.Nat32,
+  });
+  const LlmResponse = IDL.Record({
+    'model' : IDL.Text,
+    'text' : IDL.Text,
+    'usage' : IDL.Opt(LlmUsage),
+  });
   const Classification = IDL.Record({
+    'scientific_name' : IDL.Opt(IDL.Text),
+    'raw_logit' : IDL.Float32,
     'label' : IDL.Text,
     'score' : IDL.Float32,
+    'synset' : IDL.Opt(IDL.Text),
+  });
+  const ClassificationError = IDL.Record({
+    'kind' : IDL.Opt(ClassifyError),
+    'message' : IDL.Text,
   });
-  const ClassificationError = IDL.Record({ 'message' : IDL.Text });
   const ClassificationResult = IDL.Variant({
     'Ok' : IDL.Vec(Classification),
     'Err' : ClassificationError,
   });
+  const NotAnimal = IDL.Record({
+    'animal_score' : IDL.Float32,
+    'top_label' : Classification,
+  });
+  const ClassificationResultV2 = IDL.Variant({
+    'Ok' : IDL.Vec(Classification),
+    'Err' : ClassifyError,
+    'NotAnimal' : NotAnimal,
+  });
+  const LlmErrorKind = IDL.Variant({
+    'OutcallFailed' : IDL.Null,
+    'InvalidResponse' : IDL.Null,
+    'ProviderError' : IDL.Null,
+    'InvalidRequest' : IDL.Null,
+    'NotConfigured' : IDL.Null,
+  });
+  const LlmError = IDL.Record({
+    'status' : IDL.Opt(IDL.Nat16),
+    'kind' : LlmErrorKind,
+    'message' : IDL.Text,
+  });
+  const LlmResult = IDL.Variant({ 'Ok' : LlmResponse, 'Err' : LlmError });
+  const Normalization = IDL.Variant({ 'L2' : IDL.Null, 'Raw' : IDL.Null });
+  const EmbedOptions = IDL.Record({
+    'crop' : IDL.Opt(Crop),
+    'normalization' : IDL.Opt(Normalization),
+    'model_id' : IDL.Opt(IDL.Text),
+  });
+  const EmbedResult = IDL.Variant({
+    'Ok' : IDL.Vec(IDL.Float32),
+    'Err' : ClassifyError,
+  });
+  const LlmProvider = IDL.Variant({
+    'OpenAiCompatible' : IDL.Null,
+    'OpenAi' : IDL.Null,
+    'Anthropic' : IDL.Null,
+  });
+  const LlmConfig = IDL.Record({
+    'base_url' : IDL.Opt(IDL.Text),
+    'model' : IDL.Text,
+    'provider' : LlmProvider,
+    'temperature' : IDL.Opt(IDL.Float32),
+    'max_tokens' : IDL.Opt(IDL.Nat32),
+  });
+  const ReportKind = IDL.Variant({ 'Lost' : IDL.Null, 'Found' : IDL.Null });
+  const Report = IDL.Record({
+    'id' : IDL.Nat64,
+    'breeds' : IDL.Vec(Classification),
+    'contact' : IDL.Text,
+    'owner' : IDL.Principal,
+    'area' : IDL.Text,
+    'kind' : ReportKind,
+    'created_at' : IDL.Nat64,
+    'thumbnails' : IDL.Vec(IDL.Vec(IDL.Nat8)),
+  });
   const HeaderField = IDL.Tuple(IDL.Text, IDL.Text);
   const HttpRequest = IDL.Record({
     'url' : IDL.Text,
@@ -18,19 +158,268 @@ export const idlFactory = ({ IDL }) => {
   const HttpResponse = IDL.Record({
     'body' : IDL.Vec(IDL.Nat8),
     'headers' : IDL.Vec(HeaderField),
+    'upgrade' : IDL.Opt(IDL.Bool),
     'status_code' : IDL.Nat16,
   });
+  const PetMatch = IDL.Record({
+    'pet_id' : IDL.Nat64,
+    'name' : IDL.Text,
+    'similarity' : IDL.Float32,
+  });
+  const PetIdentification = IDL.Record({
+    'pet' : IDL.Opt(PetMatch),
+    'candidates' : IDL.Vec(PetMatch),
+  });
+  const IdentifyPetResult = IDL.Variant({
+    'Ok' : PetIdentification,
+    'Err' : ClassifyError,
+  });
+  const IndexImageResult = IDL.Variant({
+    'Ok' : IDL.Nat64,
+    'Err' : ClassifyError,
+  });
+  const KnowledgeBaseInfo = IDL.Record({
+    'entries' : IDL.Nat32,
+    'version' : IDL.Nat32,
+    'embedded' : IDL.Bool,
+  });
+  const ProfileSource = IDL.Variant({
+    'Llm' : IDL.Null,
+    'KnowledgeBase' : IDL.Record({ 'taxon' : IDL.Text, 'version' : IDL.Nat32 }),
+  });
+  const SpeciesProfile = IDL.Record({
+    'care_tips' : IDL.Vec(IDL.Text),
+    'source' : ProfileSource,
+    'temperament' : IDL.Text,
+    'diet' : IDL.Text,
+    'habitat' : IDL.Text,
+    'lifespan' : IDL.Text,
+    'label' : IDL.Text,
+    'summary' : IDL.Text,
+    'conservation_status' : IDL.Opt(IDL.Text),
+  });
+  const Lineage = IDL.Record({
+    'order' : IDL.Opt(IDL.Text),
+    'class' : IDL.Opt(IDL.Text),
+    'species' : IDL.Text,
+    'family' : IDL.Opt(IDL.Text),
+  });
+  const CommonName = IDL.Record({ 'name' : IDL.Text, 'locale' : IDL.Text });
+  const LabelInfo = IDL.Record({
+    'lineage' : IDL.Opt(Lineage),
+    'scientific_name' : IDL.Opt(IDL.Text),
+    'common_names' : IDL.Vec(CommonName),
+    'label' : IDL.Text,
+    'index' : IDL.Nat32,
+    'synset' : IDL.Opt(IDL.Text),
+  });
+  const LabelsResult = IDL.Variant({
+    'Ok' : IDL.Vec(LabelInfo),
+    'Err' : IDL.Text,
+  });
+  const ModelInfo = IDL.Record({
+    'id' : IDL.Text,
+    'label_count' : IDL.Nat32,
+    'embeds' : IDL.Bool,
+    'input_size' : IDL.Nat32,
+  });
+  const HistoryEntry = IDL.Record({
+    'id' : IDL.Nat64,
+    'thumbnail' : IDL.Opt(IDL.Vec(IDL.Nat8)),
+    'results' : IDL.Vec(Classification),
+    'animal_score' : IDL.Opt(IDL.Float32),
+    'timestamp' : IDL.Nat64,
+    'image_sha256' : IDL.Vec(IDL.Nat8),
+    'model_id' : IDL.Text,
+  });
+  const HistoryPage = IDL.Record({
+    'next' : IDL.Opt(IDL.Nat64),
+    'entries' : IDL.Vec(HistoryEntry),
+  });
+  const ReportMatch = IDL.Record({
+    'report_id' : IDL.Nat64,
+    'breed_agreement' : IDL.Float32,
+    'score' : IDL.Float32,
+    'similarity' : IDL.Float32,
+  });
+  const Notification = IDL.Record({
+    'id' : IDL.Nat64,
+    'report_id' : IDL.Nat64,
+    'created_at' : IDL.Nat64,
+    'candidate' : ReportMatch,
+  });
+  const InboxPage = IDL.Record({
+    'notifications' : IDL.Vec(Notification),
+    'more' : IDL.Bool,
+    'next' : IDL.Opt(IDL.Nat64),
+  });
+  const PetInfo = IDL.Record({
+    'id' : IDL.Nat64,
+    'name' : IDL.Text,
+    'registered_at' : IDL.Nat64,
+    'photos' : IDL.Nat32,
+  });
+  const NewReport = IDL.Record({
+    'contact' : IDL.Text,
+    'area' : IDL.Text,
+    'kind' : ReportKind,
+    'photos' : IDL.Vec(IDL.Vec(IDL.Nat8)),
+  });
+  const PostReportResult = IDL.Variant({
+    'Ok' : IDL.Nat64,
+    'Err' : ClassifyError,
+  });
+  const SimilarityIndexInfo = IDL.Record({
+    'rebuilding' : IDL.Bool,
+    'lists' : IDL.Nat32,
+    'entries' : IDL.Nat64,
+  });
+  const RegisterPetResult = IDL.Variant({
+    'Ok' : IDL.Nat64,
+    'Err' : ClassifyError,
+  });
+  const ReportMatchesResult = IDL.Variant({
+    'Ok' : IDL.Vec(ReportMatch),
+    'Err' : IDL.Text,
+  });
+  const SimilarImage = IDL.Record({
+    'id' : IDL.Nat64,
+    'owner' : IDL.Principal,
+    'similarity' : IDL.Float32,
+    'indexed_at' : IDL.Nat64,
+  });
+  const SearchSimilarResult = IDL.Variant({
+    'Ok' : IDL.Vec(SimilarImage),
+    'Err' : ClassifyError,
+  });
+  const SpeciesProfileResult = IDL.Variant({
+    'Ok' : SpeciesProfile,
+    'Err' : LlmError,
+  });
   return IDL.Service({
+    'acknowledge_inbox' : IDL.Func([IDL.Nat64], [IDL.Nat64], []),
+    'append_knowledge_base_chunk' : IDL.Func([IDL.Vec(IDL.Nat8)], [Result], []),
+    'append_model_chunk' : IDL.Func([IDL.Vec(IDL.Nat8)], [Result], []),
+    'begin_knowledge_base_upload' : IDL.Func(
+        [IDL.Nat64, IDL.Vec(IDL.Nat8)],
+        [Result],
+        [],
+      ),
+    'begin_model_upload' : IDL.Func(
+        [IDL.Text, ModelConfig, IDL.Nat64, IDL.Vec(IDL.Nat8)],
+        [Result],
+        [],
+      ),
+    'breed_mix' : IDL.Func(
+        [IDL.Vec(IDL.Nat8), Pet, ClassifyOptions],
+        [BreedMixResult],
+        ['query'],
+      ),
+    'cached_description' : IDL.Func(
+        [IDL.Text, IDL.Opt(IDL.Text)],
+        [IDL.Opt(LlmResponse)],
+        ['query'],
+      ),
     'classify' : IDL.Func([IDL.Vec(IDL.Nat8)], [ClassificationResult], []),
     'classify_query' : IDL.Func(
         [IDL.Vec(IDL.Nat8)],
         [ClassificationResult],
         ['query'],
       ),
+    'classify_query_v2' : IDL.Func(
+        [IDL.Vec(IDL.Nat8), ClassifyOptions],
+        [ClassificationResultV2],
+        ['query'],
+      ),
+    'classify_v2' : IDL.Func(
+        [IDL.Vec(IDL.Nat8), ClassifyOptions],
+        [ClassificationResultV2],
+        [],
+      ),
+    'classify_with' : IDL.Func(
+        [IDL.Text, IDL.Vec(IDL.Nat8)],
+        [ClassificationResult],
+        [],
+      ),
+    'clear_my_history' : IDL.Func([], [IDL.Nat64], []),
+    'close_report' : IDL.Func([IDL.Nat64], [Result], []),
+    'commit_knowledge_base_upload' : IDL.Func([], [Result], []),
+    'commit_model_upload' : IDL.Func([], [Result], []),
+    'continue_similarity_index_rebuild' : IDL.Func([], [], []),
+    'delete_history_entry' : IDL.Func([IDL.Nat64], [Result], []),
+    'describe' : IDL.Func([IDL.Text, IDL.Opt(IDL.Text)], [LlmResult], []),
+    'embed' : IDL.Func(
+        [IDL.Vec(IDL.Nat8), EmbedOptions],
+        [EmbedResult],
+        ['query'],
+      ),
+    'get_llm_config' : IDL.Func([], [LlmConfig], ['query']),
+    'get_report' : IDL.Func([IDL.Nat64], [IDL.Opt(Report)], ['query']),
+    'has_llm_credentials' : IDL.Func([], [IDL.Bool], ['query']),
     'http_request' : IDL.Func([HttpRequest], [HttpResponse], ['query']),
-    'llm' : IDL.Func([IDL.Text], [IDL.Text], []),
+    'http_request_update' : IDL.Func([HttpRequest], [HttpResponse], []),
+    'identify_my_pet' : IDL.Func(
+        [IDL.Vec(IDL.Nat8)],
+        [IdentifyPetResult],
+        ['query'],
+      ),
+    'index_image' : IDL.Func([IDL.Vec(IDL.Nat8)], [IndexImageResult], []),
+    'invalidate_descriptions' : IDL.Func([IDL.Opt(IDL.Text)], [IDL.Nat64], []),
+    'invalidate_species_profiles' : IDL.Func(
+        [IDL.Opt(IDL.Text)],
+        [IDL.Nat64],
+        [],
+      ),
+    'knowledge_base_info' : IDL.Func([], [KnowledgeBaseInfo], ['query']),
+    'knowledge_base_profile' : IDL.Func(
+        [IDL.Text],
+        [IDL.Opt(SpeciesProfile)],
+        ['query'],
+      ),
+    'list_labels' : IDL.Func([IDL.Opt(IDL.Text)], [LabelsResult], ['query']),
+    'list_models' : IDL.Func([], [IDL.Vec(ModelInfo)], ['query']),
+    'llm' : IDL.Func([IDL.Text], [LlmResult], []),
+    'my_history' : IDL.Func(
+        [IDL.Opt(IDL.Nat64), IDL.Opt(IDL.Nat32)],
+        [HistoryPage],
+        ['query'],
+      ),
+    'my_inbox' : IDL.Func(
+        [IDL.Opt(IDL.Nat64), IDL.Opt(IDL.Nat32)],
+        [InboxPage],
+        ['query'],
+      ),
+    'my_pets' : IDL.Func([], [IDL.Vec(PetInfo)], ['query']),
+    'my_reports' : IDL.Func([], [IDL.Vec(Report)], ['query']),
+    'post_report' : IDL.Func([NewReport], [PostReportResult], []),
+    'rebuild_similarity_index' : IDL.Func([], [SimilarityIndexInfo], []),
+    'register_pet' : IDL.Func(
+        [IDL.Text, IDL.Vec(IDL.Vec(IDL.Nat8))],
+        [RegisterPetResult],
+        [],
+      ),
+    'remove_from_index' : IDL.Func([IDL.Nat64], [Result], []),
+    'remove_model' : IDL.Func([IDL.Text], [Result], []),
+    'remove_pet' : IDL.Func([IDL.Nat64], [Result], []),
+    'report_matches' : IDL.Func(
+        [IDL.Nat64, IDL.Opt(IDL.Nat32)],
+        [ReportMatchesResult],
+        ['query'],
+      ),
     'run' : IDL.Func([], [ClassificationResult], ['query']),
-    'send_http_post_request' : IDL.Func([IDL.Text], [IDL.Text], ['query']),
+    'search_similar' : IDL.Func(
+        [IDL.Vec(IDL.Nat8), IDL.Opt(IDL.Nat32)],
+        [SearchSimilarResult],
+        ['query'],
+      ),
+    'set_llm_config' : IDL.Func([LlmConfig], [Result], []),
+    'set_llm_credentials' : IDL.Func(
+        [IDL.Text, IDL.Opt(LlmProvider)],
+        [Result],
+        [],
+      ),
+    'similarity_index_info' : IDL.Func([], [SimilarityIndexInfo], ['query']),
+    'species_profile' : IDL.Func([IDL.Text], [SpeciesProfileResult], []),
   });
 };
 export const init = ({ IDL }) => { return []; };
@@ -21,17 +21,48 @@ async function classify(event) {
 
   try {
     const blob = await resize(img);
+    const options = {
+      model_id: [],
+      top_k: [1],
+      min_score: [],
+      include_labels: [],
+      exclude_labels: [],
+      animals_only: [true],
+      rollup: [],
+      locale: [navigator.language],
+      crop: [],
+      thumbnail: [],
+    };
     let result;
     if (document.getElementById("replicated").checked) {
-      result = await backend.classify(new Uint8Array(blob));
+      result = await backend.classify_v2(new Uint8Array(blob), options);
     } else {
-      result = await backend.classify_query(new Uint8Array(blob));
+      result = await backend.classify_query_v2(new Uint8Array(blob), options);
     }
-    if (result.Ok) {
+    if (result.NotAnimal) {
+      message.innerText = "This does not look like an animal, it looks like a " +
+        result.NotAnimal.top_label.label + ".";
+    } else if (result.Err && result.Err.UnsupportedFormat) {
+      message.innerText = "Unsupported image format, please use one of " +
+        result.Err.UnsupportedFormat.accepted.join(", ") + ".";
+    } else if (result.Ok) {
         try {
-            result = await backend.llm(result.Ok[0].label)
-            result = JSON.parse(result.slice(0, -90))
-            render(message, result.choices[0].message.content);
+            const label = result.Ok[0].label;
+            const cached = await backend.cached_description(label, [navigator.language]);
+            const description = cached.length > 0
+                ? { Ok: cached[0] }
+                : await backend.describe(label, [navigator.language]);
+            if (description.Ok) {
+                render(message, description.Ok.text);
+            } else {
+                // Fall back to the facts of the knowledge base, which needs no outcall.
+                const profile = await backend.knowledge_base_profile(label);
+                if (profile.length > 0) {
+                    render(message, [profile[0].summary, ...profile[0].care_tips].join("\n"));
+                } else {
+                    message.innerText = "Failed to call openAI: " + description.Err.message;
+                }
+            }
         } catch (err) {
             message.innerText = "Failed to call openAI: " + JSON.stringify(err);
         }